@@ -11,9 +11,14 @@ use tracing::info;
 
 use llmcc_core::graph_builder::{GraphBuildOption, build_llmcc_graph};
 use llmcc_core::lang_def::{LanguageTrait, LanguageTraitImpl};
+use llmcc_core::scope::{Scope, ScopeStack};
+use llmcc_core::symbol::SymKindSet;
 use llmcc_core::*;
 use llmcc_resolver::{ResolverOption, bind_symbols_with, collect_symbols_with};
 
+/// Number of fuzzy `--symbol` matches to print when the query doesn't say otherwise.
+const DEFAULT_SYMBOL_MATCH_LIMIT: usize = 10;
+
 pub use options::{CommonTestOptions, GraphOptions, ProcessingOptions};
 
 fn should_skip_dir(name: &str) -> bool {
@@ -71,6 +76,9 @@ pub struct LlmccOptions {
     pub dependents: bool,
     pub recursive: bool,
     pub summary: bool,
+    /// Fuzzy-match this query against every known symbol name and print the
+    /// block for each of the top matches (see `ScopeStack::fuzzy_find`).
+    pub symbol: Option<String>,
 }
 
 pub fn run_main<L>(opts: &LlmccOptions) -> Result<Option<String>, DynError>
@@ -130,6 +138,10 @@ where
     pg.connect_blocks();
     info!("Linking units: {:.2}s", link_start.elapsed().as_secs_f64());
 
+    if let Some(query) = opts.symbol.as_deref() {
+        print_fuzzy_symbol_matches(&cc, globals, query);
+    }
+
     let output = generate_outputs(opts, &mut pg);
     info!("Total time: {:.2}s", total_start.elapsed().as_secs_f64());
 
@@ -264,6 +276,40 @@ fn log_parse_metrics(metrics: &llmcc_core::context::BuildMetrics) {
     }
 }
 
+/// Fuzzy-match `query` against every known symbol name and print the block
+/// for each of the top [`DEFAULT_SYMBOL_MATCH_LIMIT`] matches.
+fn print_fuzzy_symbol_matches<'tcx>(
+    cc: &'tcx CompileCtxt<'tcx>,
+    globals: &'tcx Scope<'tcx>,
+    query: &str,
+) {
+    let scopes = ScopeStack::new(&cc.arena, &cc.interner);
+    scopes.push(globals);
+
+    let matches = scopes.fuzzy_find(query, SymKindSet::empty(), None, DEFAULT_SYMBOL_MATCH_LIMIT);
+    if matches.is_empty() {
+        println!("No symbols match '{query}'");
+        return;
+    }
+
+    for m in &matches {
+        let name = cc.interner.resolve_owned(m.symbol.name).unwrap_or_default();
+        println!("--- {name} (score {}) ---", m.score);
+
+        let Some(block_id) = *m.symbol.block_id.read() else {
+            println!("  <no block recorded for this symbol>");
+            continue;
+        };
+        let Some(unit_index) = *m.symbol.unit_index.read() else {
+            println!("  <no compile unit recorded for this symbol>");
+            continue;
+        };
+
+        let unit = cc.compile_unit(unit_index);
+        let _ = print_llmcc_graph(block_id, unit);
+    }
+}
+
 fn generate_outputs<'tcx>(opts: &LlmccOptions, pg: &'tcx mut ProjectGraph<'tcx>) -> Option<String> {
     // Check if any graph output is requested
     let wants_dep_graph = opts.design_graph || opts.dep_graph;
@@ -13,6 +13,7 @@ static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
 use llmcc_cli::LlmccOptions;
+use llmcc_cli::options::GraphFormat;
 use llmcc_cli::{run_main, run_main_auto, LangProcessorRegistry};
 use llmcc_dot::ComponentDepth;
 use llmcc_rust::LangRust;
@@ -93,6 +94,14 @@ pub struct Cli {
     #[arg(long = "short-labels")]
     short_labels: bool,
 
+    /// Output format for the rendered graph: 'dot' or 'json'
+    #[arg(long = "format", value_enum, default_value_t = GraphFormat::Dot)]
+    format: GraphFormat,
+
+    /// Fuzzy-search symbol names and print the block for each top match
+    #[arg(long = "symbol", value_name = "QUERY")]
+    symbol: Option<String>,
+
     /// Output file path (writes to file instead of stdout)
     #[arg(short = 'o', long = "output", value_name = "FILE")]
     output: Option<String>,
@@ -120,6 +129,8 @@ pub fn run(args: Cli) -> Result<()> {
         pagerank_top_k: args.pagerank_top_k,
         cluster_by_crate: args.cluster_by_crate,
         short_labels: args.short_labels,
+        format: args.format,
+        symbol: args.symbol.clone(),
     };
 
     let result = match args.lang.as_str() {
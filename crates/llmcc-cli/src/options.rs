@@ -20,6 +20,20 @@ pub struct GraphOptions {
     /// When set, only the top K most important nodes are shown.
     #[arg(long = "pagerank-top-k")]
     pub pagerank_top_k: Option<usize>,
+
+    /// Output format for the rendered graph.
+    #[arg(long = "format", value_enum, default_value_t = GraphFormat::Dot)]
+    pub format: GraphFormat,
+}
+
+/// Graph output format: human-readable DOT or machine-readable JSON.
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GraphFormat {
+    /// Graphviz DOT, for rendering with `dot`/`xdot`/viewers.
+    #[default]
+    Dot,
+    /// A stable JSON schema of nodes and edges, for tools that don't parse DOT.
+    Json,
 }
 
 /// Common options for controlling processing behavior.
@@ -29,9 +43,68 @@ pub struct ProcessingOptions {
     #[arg(long)]
     pub parallel: bool,
 
-    /// Print IR during symbol resolution.
-    #[arg(long = "print-ir", default_value = "false")]
-    pub print_ir: bool,
+    /// Dump IR right after `collect_symbols`.
+    #[arg(long = "dump-collect")]
+    pub dump_collect: bool,
+
+    /// Dump IR right after `bind_symbols`.
+    #[arg(long = "dump-bind")]
+    pub dump_bind: bool,
+
+    /// Dump the block graph right after `build_llmcc_graph`.
+    #[arg(long = "dump-graph")]
+    pub dump_graph: bool,
+
+    /// Dump any symbols still unresolved after `link_units`.
+    #[arg(long = "dump-unresolved")]
+    pub dump_unresolved: bool,
+}
+
+/// Which pipeline stages to dump IR/graph output for.
+///
+/// Combines the `--dump-*` CLI flags on [`ProcessingOptions`] with the
+/// `LLMCC_DUMP` environment variable (a comma-separated list of stage names:
+/// `collect`, `bind`, `graph`, `unresolved`) so dumps can be toggled in tests
+/// or CI without rebuilding argv.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DumpStages {
+    pub collect: bool,
+    pub bind: bool,
+    pub graph: bool,
+    pub unresolved: bool,
+}
+
+impl DumpStages {
+    /// Resolve the dump stages for a run: CLI flags OR'd with `LLMCC_DUMP`.
+    pub fn resolve(processing: &ProcessingOptions) -> Self {
+        let mut stages = Self {
+            collect: processing.dump_collect,
+            bind: processing.dump_bind,
+            graph: processing.dump_graph,
+            unresolved: processing.dump_unresolved,
+        };
+        stages.merge_env_var(std::env::var("LLMCC_DUMP").ok().as_deref());
+        stages
+    }
+
+    fn merge_env_var(&mut self, spec: Option<&str>) {
+        let Some(spec) = spec else { return };
+        for stage in spec.split(',') {
+            match stage.trim() {
+                "" => {}
+                "collect" => self.collect = true,
+                "bind" => self.bind = true,
+                "graph" => self.graph = true,
+                "unresolved" => self.unresolved = true,
+                other => tracing::warn!("LLMCC_DUMP: ignoring unknown stage '{other}'"),
+            }
+        }
+    }
+
+    /// Whether any stage dump was requested.
+    pub fn any(&self) -> bool {
+        self.collect || self.bind || self.graph || self.unresolved
+    }
 }
 
 /// Combined common options for test runners.
@@ -66,6 +139,11 @@ impl GraphOptions {
         self.pagerank_top_k = top_k;
         self
     }
+
+    pub fn with_format(mut self, format: GraphFormat) -> Self {
+        self.format = format;
+        self
+    }
 }
 
 impl ProcessingOptions {
@@ -78,8 +156,23 @@ impl ProcessingOptions {
         self
     }
 
-    pub fn with_print_ir(mut self, print_ir: bool) -> Self {
-        self.print_ir = print_ir;
+    pub fn with_dump_collect(mut self, dump_collect: bool) -> Self {
+        self.dump_collect = dump_collect;
+        self
+    }
+
+    pub fn with_dump_bind(mut self, dump_bind: bool) -> Self {
+        self.dump_bind = dump_bind;
+        self
+    }
+
+    pub fn with_dump_graph(mut self, dump_graph: bool) -> Self {
+        self.dump_graph = dump_graph;
+        self
+    }
+
+    pub fn with_dump_unresolved(mut self, dump_unresolved: bool) -> Self {
+        self.dump_unresolved = dump_unresolved;
         self
     }
 }
@@ -1,14 +1,30 @@
-//! Output generation (DOT graphs).
+//! Output generation (DOT graphs, and a JSON equivalent for non-DOT tooling).
 
+use std::collections::HashSet;
 use std::fmt::Write;
 use std::time::Instant;
 
 use tracing::info;
 
+use llmcc_collect::{collect_edges, collect_nodes};
+use llmcc_core::BlockId;
 use llmcc_core::graph::ProjectGraph;
-use llmcc_dot::{RenderOptions, render_graph_with_options};
+use llmcc_core::pagerank::PageRanker;
+use llmcc_dot::{ComponentDepth, RenderNode, RenderOptions, render_graph_with_options};
 
 use crate::LlmccOptions;
+use crate::options::GraphFormat;
+
+/// Generate graph output for a project graph in whichever format was requested.
+pub fn generate_graph_output<'tcx>(
+    opts: &LlmccOptions,
+    pg: &'tcx ProjectGraph<'tcx>,
+) -> Option<String> {
+    match opts.format {
+        GraphFormat::Dot => generate_dot_output(opts, pg),
+        GraphFormat::Json => generate_json_output(opts, pg),
+    }
+}
 
 /// Generate DOT output for a project graph.
 pub fn generate_dot_output<'tcx>(
@@ -20,12 +36,7 @@ pub fn generate_dot_output<'tcx>(
     }
 
     let render_start = Instant::now();
-    let render_options = RenderOptions {
-        show_orphan_nodes: false,
-        pagerank_top_k: opts.pagerank_top_k,
-        cluster_by_crate: opts.cluster_by_crate,
-        short_labels: opts.short_labels,
-    };
+    let render_options = graph_render_options(opts);
 
     let result = render_graph_with_options(pg, opts.component_depth, &render_options);
 
@@ -37,6 +48,170 @@ pub fn generate_dot_output<'tcx>(
     Some(result)
 }
 
+/// Generate JSON output for a project graph (same subgraph the DOT path shows).
+pub fn generate_json_output<'tcx>(
+    opts: &LlmccOptions,
+    pg: &'tcx ProjectGraph<'tcx>,
+) -> Option<String> {
+    if !opts.graph {
+        return None;
+    }
+
+    let render_start = Instant::now();
+    let render_options = graph_render_options(opts);
+
+    let result = render_graph_json(pg, opts.component_depth, &render_options);
+
+    info!(
+        "Graph rendering (json): {:.2}s",
+        render_start.elapsed().as_secs_f64()
+    );
+
+    Some(result)
+}
+
+fn graph_render_options(opts: &LlmccOptions) -> RenderOptions {
+    RenderOptions {
+        show_orphan_nodes: false,
+        pagerank_top_k: opts.pagerank_top_k,
+        cluster_by_crate: opts.cluster_by_crate,
+        short_labels: opts.short_labels,
+    }
+}
+
+/// Render the project graph to a stable JSON schema: an array of `nodes`
+/// (`block_id`, symbol name, kind, owning `unit_index`, optional PageRank
+/// score, a `component_path`, and a `span` byte range) and an array of
+/// `edges` (`from`, `to`, and the same semantic role labels the DOT renderer
+/// draws). Filtering mirrors
+/// [`render_graph_with_options`] (`pagerank_top_k`, `show_orphan_nodes`,
+/// `cluster_by_crate`) so both formats describe the same subgraph.
+pub fn render_graph_json(
+    project: &ProjectGraph,
+    depth: ComponentDepth,
+    options: &RenderOptions,
+) -> String {
+    let nodes = collect_nodes(project);
+    if nodes.is_empty() {
+        return "{\"nodes\":[],\"edges\":[]}".to_string();
+    }
+
+    let node_set: HashSet<BlockId> = nodes.iter().map(|n| n.block_id).collect();
+    let edges = collect_edges(project, &node_set);
+
+    let (nodes, edges) = llmcc_dot::filtered_nodes_and_edges(project, &nodes, edges, options);
+
+    let scores: std::collections::HashMap<BlockId, f64> = match options.pagerank_top_k {
+        Some(_) => {
+            let node_ids: HashSet<BlockId> = nodes.iter().map(|n| n.block_id).collect();
+            PageRanker::new(project)
+                .rank()
+                .blocks
+                .into_iter()
+                .filter(|r| node_ids.contains(&r.node.block_id))
+                .map(|r| (r.node.block_id, r.score))
+                .collect()
+        }
+        None => std::collections::HashMap::new(),
+    };
+
+    let mut out = String::with_capacity(nodes.len() * 160 + edges.len() * 64 + 32);
+    out.push_str("{\"nodes\":[");
+    for (i, node) in nodes.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        let _ = write!(out, "{{\"block_id\":{}", node.block_id.0);
+        let _ = write!(out, ",\"name\":\"{}\"", escape_json(&node.name));
+        match node.sym_kind {
+            Some(kind) => {
+                let _ = write!(out, ",\"kind\":\"{kind:?}\"");
+            }
+            None => out.push_str(",\"kind\":null"),
+        }
+        let _ = write!(out, ",\"unit_index\":{}", node.block_id.unit_index());
+        match scores.get(&node.block_id) {
+            Some(score) => {
+                let _ = write!(out, ",\"pagerank_score\":{score}");
+            }
+            None => out.push_str(",\"pagerank_score\":null"),
+        }
+        match component_path(node, depth) {
+            Some(path) => {
+                let _ = write!(out, ",\"component_path\":\"{}\"", escape_json(&path));
+            }
+            None => out.push_str(",\"component_path\":null"),
+        }
+        match node.span {
+            Some((start, end)) => {
+                let _ = write!(out, ",\"span\":[{start},{end}]");
+            }
+            None => out.push_str(",\"span\":null"),
+        }
+        out.push('}');
+    }
+    out.push_str("],\"edges\":[");
+    for (i, edge) in edges.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        let _ = write!(
+            out,
+            "{{\"from\":{},\"to\":{},\"from_label\":\"{}\",\"to_label\":\"{}\"}}",
+            edge.from_id.0, edge.to_id.0, edge.from_label, edge.to_label
+        );
+    }
+    out.push_str("]}");
+    out
+}
+
+/// Cluster/component path for a node at the requested grouping depth, e.g.
+/// `"mycrate"` (Project/Crate), `"mycrate::utils"` (Module) or
+/// `"mycrate::utils/helpers.rs"` (File).
+fn component_path(node: &RenderNode, depth: ComponentDepth) -> Option<String> {
+    let mut parts: Vec<String> = Vec::new();
+    if let Some(crate_name) = &node.crate_name {
+        parts.push(crate_name.clone());
+    }
+    if matches!(depth, ComponentDepth::Project | ComponentDepth::Crate) {
+        return (!parts.is_empty()).then(|| parts.join("::"));
+    }
+
+    if let Some(module) = &node.module_path {
+        parts.push(module.clone());
+    }
+    if matches!(depth, ComponentDepth::Module) {
+        return (!parts.is_empty()).then(|| parts.join("::"));
+    }
+
+    let mut path = parts.join("::");
+    if let Some(file) = &node.file_name {
+        if !path.is_empty() {
+            path.push('/');
+        }
+        path.push_str(file);
+    }
+    (!path.is_empty()).then_some(path)
+}
+
+/// Escape a string for embedding in a JSON string literal.
+fn escape_json(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for ch in input.chars() {
+        match ch {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
 /// Merge multiple DOT graph outputs into a single graph.
 pub fn merge_dot_outputs(outputs: &[String]) -> String {
     let mut merged = String::new();
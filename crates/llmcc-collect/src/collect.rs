@@ -67,11 +67,15 @@ pub fn collect_nodes(project: &ProjectGraph) -> Vec<RenderNode> {
             let location = block
                 .opt_node()
                 .map(|node| {
-                    let line = node.start_line();
+                    let (line, _col) = unit.line_col(node.start_byte());
                     format!("{raw_path}:{line}")
                 })
                 .or(Some(raw_path.to_string()));
 
+            let span = block
+                .opt_node()
+                .map(|node| (node.start_byte() as u32, node.end_byte() as u32));
+
             // Get crate_name and module_path from BlockRoot of this unit
             let (crate_name, crate_root, module_path, module_root, file_name) = unit
                 .root_block()
@@ -96,6 +100,7 @@ pub fn collect_nodes(project: &ProjectGraph) -> Vec<RenderNode> {
                 module_root,
                 file_name,
                 sym_kind,
+                span,
             })
         })
         .collect();
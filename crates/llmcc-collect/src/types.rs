@@ -112,6 +112,8 @@ pub struct RenderNode {
     pub file_name: Option<String>,
     /// Symbol kind (Struct, Trait, Enum, Function, Method)
     pub sym_kind: Option<SymKind>,
+    /// Byte span `(start, end)` of the node's source, for clickable/navigable output.
+    pub span: Option<(u32, u32)>,
 }
 
 /// Edge with semantic labels.
@@ -5,7 +5,7 @@
 // at the bottom for how to implement your own custom parser.
 
 use std::any::Any;
-use llmcc_core::lang_def::{LanguageTrait, ParseTree, HirKind};
+use llmcc_core::lang_def::{LanguageTrait, Parse, ParseTree, HirKind};
 use llmcc_core::graph_builder::BlockKind;
 
 // ============================================================================
@@ -100,14 +100,14 @@ pub struct LangCustom {}
 
 impl LanguageTrait for LangCustom {
     /// Parse returns a generic ParseTree (in this case, our custom one)
-    fn parse(text: impl AsRef<[u8]>) -> Option<Box<dyn ParseTree>> {
+    fn parse(text: impl AsRef<[u8]>) -> Option<Parse> {
         let source = text.as_ref();
         let root = custom_parser::parse_source(source)?;
 
-        Some(Box::new(CustomParseTree {
+        Some(Parse::new(Box::new(CustomParseTree {
             root,
             source_len: source.len(),
-        }))
+        })))
     }
 
     /// Map custom token IDs to HIR kinds
@@ -327,10 +327,23 @@ pub enum BlockRelation {
     HasMethod,
     /// Method → Impl/Trait/Class that owns it
     MethodOf,
-    /// Type → Trait it implements
+    /// Impl/Trait → associated type blocks
+    HasAssocType,
+    /// Associated type → Impl/Trait that owns it
+    AssocTypeOf,
+    /// Impl/Trait → associated const blocks
+    HasAssocConst,
+    /// Associated const → Impl/Trait that owns it
+    AssocConstOf,
+    /// Impl → Trait it implements
     Implements,
-    /// Trait → Types that implement it
+    /// Trait → Impl blocks that implement it
     ImplementedBy,
+    /// Impl method → the trait default/decl method it satisfies
+    Overrides,
+    /// Call resolved through a trait object → candidate method bodies across
+    /// all implementing blocks (virtual dispatch fan-out)
+    DynCall,
 
     // ========== Generic Reference ==========
     /// Uses a type/const/function
@@ -11,10 +11,11 @@ use uuid::Uuid;
 use crate::block::{BasicBlock, BlockArena, BlockId, reset_block_id_counter};
 use crate::block_rel::{BlockIndexMaps, BlockRelationMap};
 use crate::file::File;
+use crate::incremental::{UnitHashes, hash_unit_content};
 use crate::interner::{InternPool, InternedStr};
 use crate::ir::{Arena, HirBase, HirId, HirIdent, HirKind, HirNode};
 use crate::ir_builder::reset_hir_id_counter;
-use crate::lang_def::{LanguageTrait, ParseTree};
+use crate::lang_def::{Diagnostic, LanguageTrait, Parse, ParseTree, SyntaxError};
 use crate::scope::Scope;
 use crate::symbol::{ScopeId, SymId, Symbol, reset_scope_id_counter, reset_symbol_id_counter};
 
@@ -31,10 +32,17 @@ impl<'tcx> CompileUnit<'tcx> {
 
     /// Get the generic parse tree for this compilation unit
     pub fn parse_tree(&self) -> Option<&dyn ParseTree> {
-        self.cc
-            .parse_trees
-            .get(self.index)
-            .and_then(|t| t.as_deref())
+        self.cc.get_parse_tree(self.index)
+    }
+
+    /// Get the syntax errors recovered while parsing this compilation unit.
+    pub fn parse_errors(&self) -> Option<&[SyntaxError]> {
+        self.cc.get_parse_errors(self.index)
+    }
+
+    /// Get the [`LanguageTrait::validate`] diagnostics recorded for this compilation unit.
+    pub fn diagnostics(&self) -> Option<&[Diagnostic]> {
+        self.cc.get_diagnostics(self.index)
     }
 
     /// Access the shared string interner.
@@ -95,6 +103,11 @@ impl<'tcx> CompileUnit<'tcx> {
         self.get_text(node.start_byte(), node.end_byte())
     }
 
+    /// Convert a byte offset into this unit's source to a 1-based (line, column).
+    pub fn line_col(&self, byte_offset: usize) -> (usize, usize) {
+        self.file().line_col(byte_offset)
+    }
+
     /// Get a HIR node by ID, returning None if not found
     pub fn opt_hir_node(self, id: HirId) -> Option<HirNode<'tcx>> {
         self.cc.get_hir_node(id)
@@ -210,8 +223,9 @@ pub struct CompileCtxt<'tcx> {
     pub arena: Arena<'tcx>,
     pub interner: InternPool,
     pub files: Vec<File>,
-    /// Generic parse trees from language-specific parsers
-    pub parse_trees: Vec<Option<Box<dyn ParseTree>>>,
+    /// Generic parse trees from language-specific parsers, alongside any
+    /// syntax errors recovered while parsing each one
+    pub parse_trees: Vec<Option<Parse>>,
     pub hir_root_ids: RwLock<Vec<Option<HirId>>>,
 
     pub block_arena: BlockArena<'tcx>,
@@ -222,6 +236,12 @@ pub struct CompileCtxt<'tcx> {
 
     /// Metrics collected while building the compilation context
     pub build_metrics: BuildMetrics,
+
+    /// Content hash of each unit's source as of this build, keyed by unit
+    /// index - compare against a prior build's hashes (see
+    /// [`CompileCtxt::changed_units`]) to find units an incremental
+    /// rebuild needs to redo.
+    pub unit_hashes: RwLock<Vec<u64>>,
 }
 
 impl<'tcx> std::fmt::Debug for CompileCtxt<'tcx> {
@@ -289,6 +309,10 @@ impl<'tcx> CompileCtxt<'tcx> {
         metrics.file_read_seconds = file_read_seconds;
 
         let count = files.len();
+        let unit_hashes = files
+            .iter()
+            .map(|f| hash_unit_content(f.content()))
+            .collect();
         Ok(Self {
             arena: Arena::default(),
             interner: InternPool::default(),
@@ -299,6 +323,7 @@ impl<'tcx> CompileCtxt<'tcx> {
             related_map: BlockRelationMap::default(),
             block_indexes: RwLock::new(BlockIndexMaps::new()),
             build_metrics: metrics,
+            unit_hashes: RwLock::new(unit_hashes),
         })
     }
 
@@ -335,6 +360,10 @@ impl<'tcx> CompileCtxt<'tcx> {
         metrics.file_read_seconds = file_read_seconds;
 
         let count = files.len();
+        let unit_hashes = files
+            .iter()
+            .map(|f| hash_unit_content(f.content()))
+            .collect();
         Ok(Self {
             arena: Arena::default(),
             interner: InternPool::default(),
@@ -345,14 +374,15 @@ impl<'tcx> CompileCtxt<'tcx> {
             related_map: BlockRelationMap::default(),
             block_indexes: RwLock::new(BlockIndexMaps::new()),
             build_metrics: metrics,
+            unit_hashes: RwLock::new(unit_hashes),
         })
     }
 
     fn parse_files_with_metrics<L: LanguageTrait>(
         files: &[File],
-    ) -> (Vec<Option<Box<dyn ParseTree>>>, BuildMetrics) {
+    ) -> (Vec<Option<Parse>>, BuildMetrics) {
         struct ParseRecord {
-            tree: Option<Box<dyn ParseTree>>,
+            tree: Option<Parse>,
             elapsed: f64,
             path: Option<String>,
         }
@@ -363,7 +393,10 @@ impl<'tcx> CompileCtxt<'tcx> {
             .map(|file| {
                 let path = file.path().map(|p| p.to_string());
                 let per_file_start = Instant::now();
-                let tree = L::parse(file.content());
+                let tree = L::parse(file.content()).map(|mut parse| {
+                    L::validate(parse.tree.as_ref(), file.content(), &mut parse.diagnostics);
+                    parse
+                });
                 let elapsed = per_file_start.elapsed().as_secs_f64();
                 ParseRecord {
                     tree,
@@ -421,6 +454,19 @@ impl<'tcx> CompileCtxt<'tcx> {
         CompileUnit { cc: self, index }
     }
 
+    /// Snapshot this build's per-unit content hashes, to diff against a
+    /// later build's [`CompileCtxt::changed_units`].
+    pub fn unit_hashes(&self) -> UnitHashes {
+        UnitHashes::new(self.unit_hashes.read().clone())
+    }
+
+    /// Indices of units whose content hash differs from `previous` (a
+    /// snapshot taken via [`CompileCtxt::unit_hashes`] on an earlier build
+    /// of the same project). A newly added file counts as changed.
+    pub fn changed_units(&self, previous: &UnitHashes) -> Vec<usize> {
+        previous.changed_since(&self.unit_hashes.read())
+    }
+
     pub fn create_unit_globals(&'tcx self, owner: HirId) -> &'tcx Scope<'tcx> {
         // Scope already in Arena
         self.arena
@@ -531,7 +577,26 @@ impl<'tcx> CompileCtxt<'tcx> {
 
     /// Get the generic parse tree for a specific file
     pub fn get_parse_tree(&self, index: usize) -> Option<&dyn ParseTree> {
-        self.parse_trees.get(index).and_then(|t| t.as_deref())
+        self.parse_trees
+            .get(index)
+            .and_then(|p| p.as_ref())
+            .map(|p| p.tree.as_ref())
+    }
+
+    /// Get the syntax errors recovered while parsing a specific file
+    pub fn get_parse_errors(&self, index: usize) -> Option<&[SyntaxError]> {
+        self.parse_trees
+            .get(index)
+            .and_then(|p| p.as_ref())
+            .map(|p| p.errors.as_slice())
+    }
+
+    /// Get the [`LanguageTrait::validate`] diagnostics recorded for a specific file.
+    pub fn get_diagnostics(&self, index: usize) -> Option<&[Diagnostic]> {
+        self.parse_trees
+            .get(index)
+            .and_then(|p| p.as_ref())
+            .map(|p| p.diagnostics.as_slice())
     }
 
     /// Get all file paths from the compilation context
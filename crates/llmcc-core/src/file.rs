@@ -10,6 +10,22 @@ pub struct FileId {
     pub path: Option<String>,
     content: Arc<[u8]>,
     pub content_hash: u64,
+    /// Byte offset of the start of each line (line_starts[0] == 0), built once
+    /// from `content` so `line_col` can binary-search instead of rescanning.
+    line_starts: Arc<[usize]>,
+}
+
+/// Byte offset of the start of each line in `content` (first entry is always 0).
+fn compute_line_starts(content: &[u8]) -> Arc<[usize]> {
+    let mut starts = vec![0usize];
+    starts.extend(
+        content
+            .iter()
+            .enumerate()
+            .filter(|(_, &b)| b == b'\n')
+            .map(|(i, _)| i + 1),
+    );
+    Arc::from(starts)
 }
 
 impl FileId {
@@ -22,11 +38,13 @@ impl FileId {
         let mut hasher = DefaultHasher::new();
         content.hash(&mut hasher);
         let content_hash = hasher.finish();
+        let line_starts = compute_line_starts(&content);
 
         Ok(FileId {
             path: Some(path),
             content: Arc::from(content),
             content_hash,
+            line_starts,
         })
     }
 
@@ -45,11 +63,13 @@ impl FileId {
         let mut hasher = DefaultHasher::new();
         content.hash(&mut hasher);
         let content_hash = hasher.finish();
+        let line_starts = compute_line_starts(&content);
 
         Ok(FileId {
             path: Some(logical_path),
             content: Arc::from(content),
             content_hash,
+            line_starts,
         })
     }
 
@@ -57,11 +77,13 @@ impl FileId {
         let mut hasher = DefaultHasher::new();
         hasher.write(&content);
         let content_hash = hasher.finish();
+        let line_starts = compute_line_starts(&content);
 
         FileId {
             path: None,
             content: Arc::from(content),
             content_hash,
+            line_starts,
         }
     }
 
@@ -69,6 +91,18 @@ impl FileId {
         self.content.as_ref()
     }
 
+    /// Convert a byte offset into this file's content to a 1-based (line, column).
+    /// Binary-searches the newline index built when the file was read, so this
+    /// is O(log lines) instead of rescanning the source on every call.
+    pub fn line_col(&self, byte_offset: usize) -> (usize, usize) {
+        let offset = byte_offset.min(self.content.len());
+        let line_idx = match self.line_starts.binary_search(&offset) {
+            Ok(i) => i,
+            Err(i) => i - 1,
+        };
+        (line_idx + 1, offset - self.line_starts[line_idx] + 1)
+    }
+
     pub fn get_text(&self, start_byte: usize, end_byte: usize) -> Option<String> {
         let content_bytes = self.content();
 
@@ -133,4 +167,9 @@ impl File {
     pub fn path(&self) -> Option<&str> {
         self.file.path.as_deref()
     }
+
+    /// Convert a byte offset into this file's content to a 1-based (line, column).
+    pub fn line_col(&self, byte_offset: usize) -> (usize, usize) {
+        self.file.line_col(byte_offset)
+    }
 }
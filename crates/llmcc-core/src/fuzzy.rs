@@ -0,0 +1,161 @@
+//! Fuzzy symbol-name matching: a cheap 64-bit "char bag" prefilter followed
+//! by a DP-scored subsequence match. Used to find symbols when a query is a
+//! partial or misspelled name rather than an exact interned-suffix match.
+
+const BASE_MATCH_SCORE: i32 = 16;
+const WORD_BOUNDARY_BONUS: i32 = 24;
+const CONSECUTIVE_BONUS: i32 = 20;
+const LEADING_GAP_PENALTY: i32 = 2;
+const UNREACHABLE: i32 = i32::MIN / 2;
+
+/// Bitset of which lowercased ASCII letters (`a`-`z`) appear in `text`.
+/// Precompute once per candidate name; reject any candidate whose bag does
+/// not contain every letter of the query before running the scored match.
+pub fn char_bag(text: &str) -> u64 {
+    let mut bag = 0u64;
+    for ch in text.chars() {
+        let lower = ch.to_ascii_lowercase();
+        if lower.is_ascii_lowercase() {
+            bag |= 1 << (lower as u8 - b'a');
+        }
+    }
+    bag
+}
+
+/// Does `candidate_bag` contain every letter set in `query_bag`?
+#[inline]
+pub fn bag_contains(candidate_bag: u64, query_bag: u64) -> bool {
+    candidate_bag & query_bag == query_bag
+}
+
+/// Score how well `query` matches `candidate` as a fuzzy (case-insensitive)
+/// subsequence. Returns `None` if `query` isn't a subsequence of `candidate`
+/// at all. Higher scores are better matches: each matched char earns a base
+/// score, with bonuses for landing on a word boundary (start of string,
+/// after `_`, or a lower->upper camelCase transition) and for immediately
+/// following the previous query char's match; leading gaps before the first
+/// match are penalized.
+pub fn score_match(query: &str, candidate: &str) -> Option<i32> {
+    let query: Vec<char> = query.chars().map(|c| c.to_ascii_lowercase()).collect();
+    let candidate: Vec<char> = candidate.chars().collect();
+    if query.is_empty() || candidate.is_empty() {
+        return None;
+    }
+
+    #[derive(Clone, Copy)]
+    struct Cell {
+        score: i32,
+        matched: bool,
+    }
+
+    let n = candidate.len();
+    let m = query.len();
+    let mut dp = vec![
+        vec![
+            Cell {
+                score: UNREACHABLE,
+                matched: false
+            };
+            m + 1
+        ];
+        n + 1
+    ];
+    for row in dp.iter_mut() {
+        row[0] = Cell {
+            score: 0,
+            matched: false,
+        };
+    }
+
+    for i in 1..=n {
+        let lower = candidate[i - 1].to_ascii_lowercase();
+        let boundary = is_word_boundary(&candidate, i - 1);
+        for j in 1..=m {
+            let skip = dp[i - 1][j];
+
+            let matched_here = (lower == query[j - 1]).then_some(dp[i - 1][j - 1]).and_then(
+                |prev| {
+                    if prev.score <= UNREACHABLE {
+                        return None;
+                    }
+                    let mut bonus = BASE_MATCH_SCORE;
+                    if boundary {
+                        bonus += WORD_BOUNDARY_BONUS;
+                    }
+                    if prev.matched {
+                        bonus += CONSECUTIVE_BONUS;
+                    }
+                    if j == 1 {
+                        bonus -= (i - 1) as i32 * LEADING_GAP_PENALTY;
+                    }
+                    Some(Cell {
+                        score: prev.score + bonus,
+                        matched: true,
+                    })
+                },
+            );
+
+            dp[i][j] = match matched_here {
+                Some(cell) if cell.score >= skip.score => cell,
+                _ => Cell {
+                    score: skip.score,
+                    matched: false,
+                },
+            };
+        }
+    }
+
+    let best = dp[n][m];
+    (best.score > UNREACHABLE).then_some(best.score)
+}
+
+/// Is `candidate[index]` the start of a "word" within the name - the very
+/// first char, the char right after an `_`, or a camelCase lower->upper step?
+fn is_word_boundary(candidate: &[char], index: usize) -> bool {
+    if index == 0 {
+        return true;
+    }
+    let prev = candidate[index - 1];
+    if prev == '_' {
+        return true;
+    }
+    prev.is_lowercase() && candidate[index].is_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn char_bag_rejects_missing_letters() {
+        let candidate = char_bag("parse_files");
+        let query = char_bag("pfz");
+        assert!(!bag_contains(candidate, query));
+
+        let query = char_bag("prs");
+        assert!(bag_contains(candidate, query));
+    }
+
+    #[test]
+    fn score_match_requires_subsequence() {
+        assert!(score_match("xyz", "parse_files").is_none());
+        assert!(score_match("pfs", "parse_files").is_some());
+    }
+
+    #[test]
+    fn score_match_prefers_word_boundaries_and_consecutive_runs() {
+        // "bs" as a contiguous prefix of word boundaries in "build_symbols"
+        // should score higher than the same letters scattered in a name
+        // that puts them on non-boundary positions.
+        let boundary_score = score_match("bs", "build_symbols").unwrap();
+        let scattered_score = score_match("bs", "abbreviations").unwrap();
+        assert!(boundary_score > scattered_score);
+    }
+
+    #[test]
+    fn score_match_penalizes_leading_gap() {
+        let early = score_match("sym", "symbol_table").unwrap();
+        let late = score_match("sym", "lookup_symbol").unwrap();
+        assert!(early > late);
+    }
+}
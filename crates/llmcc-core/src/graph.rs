@@ -1,6 +1,6 @@
 use rayon::prelude::*;
 
-use crate::block::{BasicBlock, BlockId, BlockRelation};
+use crate::block::{BasicBlock, BlockId, BlockKind, BlockRelation};
 use crate::context::{CompileCtxt, CompileUnit};
 
 #[derive(Debug, Clone)]
@@ -23,6 +23,18 @@ impl UnitGraph {
     pub fn root(&self) -> BlockId {
         self.root
     }
+
+    /// Byte span `(start, end)` of `block`'s source node, if `block` exists
+    /// and carries a HIR node (root/synthetic blocks don't). `block` is looked
+    /// up in the shared block arena; it is not checked to belong to this unit.
+    pub fn span_of<'tcx>(&self, cc: &'tcx CompileCtxt<'tcx>, block: BlockId) -> Option<(usize, usize)> {
+        let unit = CompileUnit {
+            cc,
+            index: self.unit_index,
+        };
+        unit.opt_bb(block)
+            .and_then(|bb| bb.opt_node().map(|node| (node.start_byte(), node.end_byte())))
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -31,14 +43,38 @@ pub struct UnitNode {
     pub block_id: BlockId,
 }
 
+/// Filter over a relation's source block `(kind, name)`, checked before a
+/// [`RelationObserver`]'s callback runs.
+pub type ObserverPredicate<'tcx> = Box<dyn Fn(BlockKind, &str) -> bool + Send + Sync + 'tcx>;
+
+/// A relation trigger registered via [`ProjectGraph::observe`]: fires
+/// `callback(from, to)` whenever `relation` is established during
+/// `connect_blocks()`, provided `predicate` (if any) accepts the source
+/// block's `(kind, name)`.
+pub struct RelationObserver<'tcx> {
+    relation: BlockRelation,
+    predicate: Option<ObserverPredicate<'tcx>>,
+    callback: Box<dyn Fn(BlockId, BlockId) + Send + Sync + 'tcx>,
+}
+
 /// ProjectGraph represents a complete compilation project with all units
 /// and their inter-dependencies.
-#[derive(Debug)]
 pub struct ProjectGraph<'tcx> {
     /// Reference to the compilation context containing all symbols
     pub cc: &'tcx CompileCtxt<'tcx>,
     /// Per-unit graphs containing blocks and intra-unit relations
     units: Vec<UnitGraph>,
+    /// Triggers fired as relations are established during `connect_blocks()`
+    observers: Vec<RelationObserver<'tcx>>,
+}
+
+impl<'tcx> std::fmt::Debug for ProjectGraph<'tcx> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProjectGraph")
+            .field("units", &self.units)
+            .field("observers", &self.observers.len())
+            .finish()
+    }
 }
 
 impl<'tcx> ProjectGraph<'tcx> {
@@ -46,9 +82,27 @@ impl<'tcx> ProjectGraph<'tcx> {
         Self {
             cc,
             units: Vec::new(),
+            observers: Vec::new(),
         }
     }
 
+    /// Register an observer that fires whenever `relation` is established
+    /// during `connect_blocks()`. `predicate`, if given, filters by the
+    /// source block's `(kind, name)` before `callback` runs - e.g. "only
+    /// `ImplFor` edges whose source block is a `Struct`".
+    pub fn observe(
+        &mut self,
+        relation: BlockRelation,
+        predicate: Option<ObserverPredicate<'tcx>>,
+        callback: impl Fn(BlockId, BlockId) + Send + Sync + 'tcx,
+    ) {
+        self.observers.push(RelationObserver {
+            relation,
+            predicate,
+            callback: Box::new(callback),
+        });
+    }
+
     pub fn add_child(&mut self, graph: UnitGraph) {
         self.units.push(graph);
         self.units.sort_by_key(|g| g.unit_index());
@@ -91,6 +145,56 @@ impl<'tcx> ProjectGraph<'tcx> {
             let root_block = unit.bb(unit_graph.root());
             self.dfs_connect(&unit, &root_block, None);
         });
+
+        // Dynamic dispatch needs every impl's `ImplementedBy` edge to already
+        // be in place, so it runs as a second pass once all units are connected.
+        self.connect_dyn_calls();
+    }
+
+    /// Fan out calls resolved through a trait object. A call whose callee is
+    /// a method declared directly on a `Trait` block (rather than on a
+    /// concrete `Impl`) is a virtual dispatch through that trait - link the
+    /// call site to the matching method on every block that implements it.
+    fn connect_dyn_calls(&self) {
+        for (unit_index, _name, call_id) in self.cc.find_blocks_by_kind(BlockKind::Call) {
+            let unit = CompileUnit {
+                cc: self.cc,
+                index: unit_index,
+            };
+            let BasicBlock::Call(call) = unit.bb(call_id) else {
+                continue;
+            };
+            let Some(callee_id) = call.get_callee() else {
+                continue;
+            };
+            let BasicBlock::Func(callee_func) = unit.bb(callee_id) else {
+                continue;
+            };
+            let Some(trait_id) = *callee_func.base.parent.read() else {
+                continue;
+            };
+            if !matches!(unit.bb(trait_id), BasicBlock::Trait(_)) {
+                continue;
+            }
+
+            for impl_id in self.cc.related_map.get_related(trait_id, BlockRelation::ImplementedBy) {
+                let Some((impl_unit_index, _, _)) = self.cc.get_block_info(impl_id) else {
+                    continue;
+                };
+                let impl_unit = CompileUnit {
+                    cc: self.cc,
+                    index: impl_unit_index,
+                };
+                let BasicBlock::Impl(impl_block) = impl_unit.bb(impl_id) else {
+                    continue;
+                };
+                for method_id in impl_block.get_methods() {
+                    if Self::func_name(&impl_unit, method_id).as_deref() == Some(callee_func.name.as_str()) {
+                        self.add_relation(call_id, BlockRelation::DynCall, method_id);
+                    }
+                }
+            }
+        }
     }
 
     /// Recursively connect blocks in pre-order DFS traversal.
@@ -131,6 +235,30 @@ impl<'tcx> ProjectGraph<'tcx> {
     #[inline]
     fn add_relation(&self, from: BlockId, relation: BlockRelation, to: BlockId) {
         self.cc.related_map.add_relation_impl(from, relation, to);
+        self.notify_observers(from, relation, to);
+    }
+
+    /// Fire every registered [`RelationObserver`] whose relation matches and
+    /// whose predicate (if any) accepts the source block's `(kind, name)`.
+    fn notify_observers(&self, from: BlockId, relation: BlockRelation, to: BlockId) {
+        if self.observers.is_empty() {
+            return;
+        }
+
+        for observer in &self.observers {
+            if observer.relation != relation {
+                continue;
+            }
+            if let Some(predicate) = &observer.predicate {
+                let Some((_, name, kind)) = self.cc.get_block_info(from) else {
+                    continue;
+                };
+                if !predicate(kind, name.as_deref().unwrap_or("")) {
+                    continue;
+                }
+            }
+            (observer.callback)(from, to);
+        }
     }
 
     /// Link function/method relationships.
@@ -283,6 +411,9 @@ impl<'tcx> ProjectGraph<'tcx> {
             self.add_relation(method_id, BlockRelation::MethodOf, block_id);
         }
 
+        // Associated types/consts
+        self.link_assoc_items(unit, block_id, &impl_block.base);
+
         // Target type - resolve from symbol if block_id wasn't available during building
         let target_id = impl_block.get_target().or_else(|| {
             impl_block.target_sym.and_then(|sym| sym.block_id())
@@ -327,6 +458,50 @@ impl<'tcx> ProjectGraph<'tcx> {
             impl_block.set_trait_ref(trait_id);
             self.add_relation(block_id, BlockRelation::Implements, trait_id);
             self.add_relation(trait_id, BlockRelation::ImplementedBy, block_id);
+
+            // Overrides - match each impl method against the trait's own
+            // method of the same name (its default/decl method).
+            if let BasicBlock::Trait(trait_block) = unit.bb(trait_id) {
+                for method_id in impl_block.get_methods() {
+                    let Some(method_name) = Self::func_name(unit, method_id) else {
+                        continue;
+                    };
+                    for trait_method_id in trait_block.get_methods() {
+                        if Self::func_name(unit, trait_method_id).as_deref() == Some(method_name.as_str()) {
+                            self.add_relation(method_id, BlockRelation::Overrides, trait_method_id);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Name of a `Func` block, if `id` refers to one.
+    fn func_name(unit: &CompileUnit<'tcx>, id: BlockId) -> Option<String> {
+        match unit.bb(id) {
+            BasicBlock::Func(func) => Some(func.name.clone()),
+            _ => None,
+        }
+    }
+
+    /// Associated-item relations for an Impl/Trait block: its `Const`
+    /// children become `HasAssocConst`/`AssocConstOf`, its `Alias` children
+    /// (associated type bindings/decls) become `HasAssocType`/`AssocTypeOf`.
+    /// Split out from `HasMethod`/`MethodOf` since an associated type binding
+    /// isn't a method.
+    fn link_assoc_items(&self, unit: &CompileUnit<'tcx>, block_id: BlockId, base: &crate::block::BlockBase<'tcx>) {
+        for child_id in base.get_children() {
+            match unit.bb(child_id).kind() {
+                BlockKind::Const => {
+                    self.add_relation(block_id, BlockRelation::HasAssocConst, child_id);
+                    self.add_relation(child_id, BlockRelation::AssocConstOf, block_id);
+                }
+                BlockKind::Alias => {
+                    self.add_relation(block_id, BlockRelation::HasAssocType, child_id);
+                    self.add_relation(child_id, BlockRelation::AssocTypeOf, block_id);
+                }
+                _ => {}
+            }
         }
     }
 
@@ -338,6 +513,9 @@ impl<'tcx> ProjectGraph<'tcx> {
             self.add_relation(method_id, BlockRelation::MethodOf, block_id);
         }
 
+        // Associated types/consts
+        self.link_assoc_items(unit, block_id, &trait_block.base);
+
         // Type parameter bounds: for `trait Foo<T: Bar>`, create edge Bar -> Foo
         // Bar (bound) is used by Foo (this trait)
         if let Some(trait_sym) = trait_block.base.symbol {
@@ -539,3 +717,78 @@ impl<'tcx> ProjectGraph<'tcx> {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::LangSimple;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn observe_fires_only_for_the_registered_relation() {
+        let cc = CompileCtxt::from_sources::<LangSimple>(&[b"".to_vec()]);
+        let mut pg = ProjectGraph::new(&cc);
+
+        let seen = Arc::new(AtomicUsize::new(0));
+        let seen_cb = seen.clone();
+        pg.observe(BlockRelation::Calls, None, move |from, to| {
+            assert_eq!(from, BlockId(1));
+            assert_eq!(to, BlockId(2));
+            seen_cb.fetch_add(1, Ordering::SeqCst);
+        });
+
+        pg.add_relation(BlockId(1), BlockRelation::CalledBy, BlockId(2));
+        assert_eq!(
+            seen.load(Ordering::SeqCst),
+            0,
+            "a differing relation must not fire the observer"
+        );
+
+        pg.add_relation(BlockId(1), BlockRelation::Calls, BlockId(2));
+        assert_eq!(
+            seen.load(Ordering::SeqCst),
+            1,
+            "the registered relation must fire the observer exactly once"
+        );
+    }
+
+    #[test]
+    fn observe_predicate_filters_by_source_kind_and_name() {
+        let cc = CompileCtxt::from_sources::<LangSimple>(&[b"".to_vec()]);
+        cc.block_indexes
+            .write()
+            .insert_block(BlockId(10), Some("caller".to_string()), BlockKind::Func, 0);
+        cc.block_indexes
+            .write()
+            .insert_block(BlockId(11), Some("CallerStruct".to_string()), BlockKind::Class, 0);
+
+        let mut pg = ProjectGraph::new(&cc);
+
+        let seen = Arc::new(AtomicUsize::new(0));
+        let seen_cb = seen.clone();
+        pg.observe(
+            BlockRelation::Calls,
+            Some(Box::new(|kind, name| kind == BlockKind::Func && name == "caller")),
+            move |_, _| {
+                seen_cb.fetch_add(1, Ordering::SeqCst);
+            },
+        );
+
+        // Source block is a Class, not a Func - the predicate should reject it.
+        pg.add_relation(BlockId(11), BlockRelation::Calls, BlockId(99));
+        assert_eq!(seen.load(Ordering::SeqCst), 0);
+
+        // Source block matches the predicate - the observer should fire.
+        pg.add_relation(BlockId(10), BlockRelation::Calls, BlockId(99));
+        assert_eq!(seen.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn observe_with_no_observers_registered_is_a_no_op() {
+        let cc = CompileCtxt::from_sources::<LangSimple>(&[b"".to_vec()]);
+        let mut pg = ProjectGraph::new(&cc);
+        // Must not panic even though nothing is listening.
+        pg.add_relation(BlockId(1), BlockRelation::Calls, BlockId(2));
+    }
+}
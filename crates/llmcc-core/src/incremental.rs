@@ -0,0 +1,162 @@
+//! Bookkeeping for incremental rebuilds: per-unit content hashes plus a
+//! transitive "what needs redoing" query over a caller-supplied
+//! reverse-dependency map.
+//!
+//! This module only decides *which* units changed and *which* units a
+//! change reaches; it deliberately stops short of re-driving
+//! `collect_symbols`/`bind_symbols`/`build_llmcc_graph` or touching
+//! `ProjectGraph` itself. The cross-unit linking pipeline that would
+//! consume this (`CompileCtxt::create_graph` / `ProjectGraph::link_units`,
+//! exercised by `crates/llmcc-rust/tests/graph_linking.rs`) isn't
+//! implemented in this tree yet - once it lands, drive its per-unit
+//! rebuild from [`affected_units`] instead of always doing a full rebuild.
+//!
+//! # Known limitations
+//!
+//! This is hash-diff and transitive-closure bookkeeping only, not the full
+//! incremental subsystem an editor/watch caller would want. Specifically,
+//! not implemented here or anywhere downstream:
+//! - a revision counter - callers compare two [`UnitHashes`] snapshots
+//!   directly, there's no notion of "build N" to key a cache on;
+//! - deriving [`ReverseDependencyMap`] from `link_units`'s resolved
+//!   cross-unit symbol references - callers must build it themselves from
+//!   whatever they have;
+//! - actually skipping `collect_symbols`/`bind_symbols`/`build_llmcc_graph`
+//!   for unaffected units - every rebuild still does all of them, full,
+//!   whether or not the caller used [`affected_units`] to narrow the set;
+//! - the byte-identical-rebuild "verify" mode (assert the incrementally
+//!   updated `ProjectGraph`'s edge set equals a from-scratch rebuild's).
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+/// Content hash for a single compile unit, used to decide whether it needs
+/// to be re-parsed/re-bound on an incremental rebuild.
+pub fn hash_unit_content(bytes: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Per-unit content hashes captured after a build, used to detect which
+/// units changed before the next one.
+#[derive(Debug, Clone, Default)]
+pub struct UnitHashes {
+    hashes: Vec<u64>,
+}
+
+impl UnitHashes {
+    pub fn new(hashes: Vec<u64>) -> Self {
+        Self { hashes }
+    }
+
+    pub fn hashes(&self) -> &[u64] {
+        &self.hashes
+    }
+
+    /// Indices whose hash differs between `self` (the previous build) and
+    /// `current`. A unit outside `self`'s range (e.g. a newly added file)
+    /// counts as changed.
+    pub fn changed_since(&self, current: &[u64]) -> Vec<usize> {
+        current
+            .iter()
+            .enumerate()
+            .filter(|(index, hash)| self.hashes.get(*index) != Some(*hash))
+            .map(|(index, _)| index)
+            .collect()
+    }
+}
+
+/// Maps a unit index to the unit indices that consume one of its exported
+/// symbols, i.e. the set of units a change to this one can ripple into.
+/// Callers derive this from whatever resolves cross-unit symbol references
+/// (the linking step, once it exists).
+#[derive(Debug, Clone, Default)]
+pub struct ReverseDependencyMap {
+    dependents: HashMap<usize, HashSet<usize>>,
+}
+
+impl ReverseDependencyMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record that `dependent` consumes an exported symbol from `dependency`.
+    pub fn add_dependency(&mut self, dependency: usize, dependent: usize) {
+        if dependency != dependent {
+            self.dependents
+                .entry(dependency)
+                .or_default()
+                .insert(dependent);
+        }
+    }
+
+    /// Units that directly consume an exported symbol from `unit`.
+    pub fn direct_dependents(&self, unit: usize) -> impl Iterator<Item = usize> + '_ {
+        self.dependents.get(&unit).into_iter().flatten().copied()
+    }
+}
+
+/// Expand `changed` to the full set of units that need rebuilding: every
+/// changed unit plus, transitively, every unit that depends on one of
+/// their exported symbols.
+pub fn affected_units(changed: &[usize], deps: &ReverseDependencyMap) -> HashSet<usize> {
+    let mut affected: HashSet<usize> = changed.iter().copied().collect();
+    let mut frontier: Vec<usize> = changed.to_vec();
+
+    while let Some(unit) = frontier.pop() {
+        for dependent in deps.direct_dependents(unit) {
+            if affected.insert(dependent) {
+                frontier.push(dependent);
+            }
+        }
+    }
+
+    affected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn changed_since_flags_differing_and_added_units() {
+        let previous = UnitHashes::new(vec![1, 2, 3]);
+        let current = vec![1, 20, 3, 4];
+        assert_eq!(previous.changed_since(&current), vec![1, 3]);
+    }
+
+    #[test]
+    fn changed_since_is_empty_when_nothing_changed() {
+        let previous = UnitHashes::new(vec![1, 2, 3]);
+        assert!(previous.changed_since(&[1, 2, 3]).is_empty());
+    }
+
+    #[test]
+    fn hash_unit_content_is_stable_and_content_sensitive() {
+        let a = hash_unit_content(b"def foo(): pass");
+        let b = hash_unit_content(b"def foo(): pass");
+        let c = hash_unit_content(b"def bar(): pass");
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn affected_units_expands_transitively_and_stops_at_cycles() {
+        let mut deps = ReverseDependencyMap::new();
+        deps.add_dependency(0, 1);
+        deps.add_dependency(1, 2);
+        deps.add_dependency(2, 0);
+
+        let affected = affected_units(&[0], &deps);
+        assert_eq!(affected, HashSet::from([0, 1, 2]));
+    }
+
+    #[test]
+    fn affected_units_is_unchanged_reflexive() {
+        let deps = ReverseDependencyMap::new();
+        let affected = affected_units(&[], &deps);
+        assert!(affected.is_empty());
+    }
+}
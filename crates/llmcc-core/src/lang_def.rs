@@ -19,12 +19,58 @@ pub trait ParseTree: Send + Sync + 'static {
     fn root_node(&self) -> Option<Box<dyn ParseNode + '_>> {
         None
     }
+
+    /// Apply an incremental edit to this tree in place, ahead of an
+    /// incremental `LanguageTrait::reparse`.
+    ///
+    /// # Default
+    /// No-op. Implementations with no cheaper way to stay in sync than a
+    /// full reparse can leave this unimplemented.
+    fn apply_edit(&mut self, _edit: &Edit) {}
+}
+
+/// A byte-range edit to apply to a previously parsed tree, expressed purely
+/// in the byte offsets `ParseNode::start_byte`/`end_byte` already use, so
+/// callers don't need to track line/column state themselves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Edit {
+    pub start_byte: usize,
+    pub old_end_byte: usize,
+    pub new_end_byte: usize,
 }
 
 /// Default implementation wrapping tree-sitter Tree
 #[derive(Debug, Clone)]
 pub struct TreeSitterParseTree {
     pub tree: ::tree_sitter::Tree,
+    /// The exact source bytes `tree` was parsed from. Kept alongside the
+    /// tree so `apply_edit` can derive `tree_sitter::Point`s for incremental
+    /// edits without the caller having to re-scan from scratch.
+    source: Vec<u8>,
+}
+
+impl TreeSitterParseTree {
+    /// Wrap a parsed tree together with the source bytes it was parsed from.
+    pub fn new(tree: ::tree_sitter::Tree, source: impl Into<Vec<u8>>) -> Self {
+        Self {
+            tree,
+            source: source.into(),
+        }
+    }
+
+    /// Row/column of `byte` within `source`, clamped to its length.
+    fn point_at(source: &[u8], byte: usize) -> ::tree_sitter::Point {
+        let byte = byte.min(source.len());
+        let mut row = 0;
+        let mut line_start = 0;
+        for (i, &b) in source[..byte].iter().enumerate() {
+            if b == b'\n' {
+                row += 1;
+                line_start = i + 1;
+            }
+        }
+        ::tree_sitter::Point::new(row, byte - line_start)
+    }
 }
 
 impl ParseTree for TreeSitterParseTree {
@@ -39,6 +85,35 @@ impl ParseTree for TreeSitterParseTree {
     fn root_node(&self) -> Option<Box<dyn ParseNode + '_>> {
         Some(Box::new(TreeSitterParseNode::new(self.tree.root_node())))
     }
+
+    fn apply_edit(&mut self, edit: &Edit) {
+        // `source` is the pre-edit text, so it's the correct reference for
+        // `start_position`/`old_end_position`. It's also the best reference
+        // we have for `new_end_position` since `apply_edit` isn't given the
+        // post-edit text; `LanguageTrait::reparse` re-derives everything
+        // precisely once it parses the real new text.
+        let input_edit = ::tree_sitter::InputEdit {
+            start_byte: edit.start_byte,
+            old_end_byte: edit.old_end_byte,
+            new_end_byte: edit.new_end_byte,
+            start_position: Self::point_at(&self.source, edit.start_byte),
+            old_end_position: Self::point_at(&self.source, edit.old_end_byte),
+            new_end_position: Self::point_at(&self.source, edit.new_end_byte),
+        };
+        self.tree.edit(&input_edit);
+    }
+}
+
+/// Result of [`ParseNode::leaf_at_offset`]: the leaf (or pair of adjacent
+/// leaves, at a shared boundary) covering a given byte offset.
+pub enum LeafAtOffset<'a> {
+    /// The offset falls outside the node's span.
+    None,
+    /// Exactly one leaf covers the offset, including at the node's own
+    /// start or end edge.
+    Single(Box<dyn ParseNode + 'a>),
+    /// The offset sits exactly on the boundary shared by two adjacent leaves.
+    Between(Box<dyn ParseNode + 'a>, Box<dyn ParseNode + 'a>),
 }
 
 /// Generic trait for parse tree nodes (individual AST nodes).
@@ -108,6 +183,145 @@ pub trait ParseNode: Send + Sync {
         None
     }
 
+    /// Produce an owned, boxed copy of this exact node (not its subtree) -
+    /// implementations typically just re-wrap the same underlying handle,
+    /// the way `child`/`parent` already do for their results. Needed so
+    /// default methods like `leaf_at_offset` can hand back `self` as a
+    /// boxed `ParseNode` when no child narrows the search further.
+    fn boxed(&self) -> Box<dyn ParseNode + '_>;
+
+    /// Get the exact source text this node spans.
+    ///
+    /// # Default
+    /// Slices `source[start_byte()..end_byte()]`, clamped to `source`'s bounds.
+    fn text<'s>(&self, source: &'s [u8]) -> &'s str {
+        let start = self.start_byte().min(source.len());
+        let end = self.end_byte().min(source.len()).max(start);
+        std::str::from_utf8(&source[start..end]).unwrap_or_default()
+    }
+
+    /// Iterate this node's leaf descendants in source order, including
+    /// `is_extra()` trivia (whitespace/comments) - the concrete syntax
+    /// tree's token stream, rather than just the named nodes the IR builder
+    /// walks.
+    ///
+    /// # Default
+    /// Empty. Parsers that can walk leaves natively (e.g. via a tree-sitter
+    /// cursor) should override this.
+    fn tokens(&self) -> Box<dyn Iterator<Item = Box<dyn ParseNode + '_>> + '_> {
+        Box::new(std::iter::empty())
+    }
+
+    /// Get the run of extra/anonymous leaves immediately preceding this
+    /// node among its siblings (e.g. the whitespace/comments right before a
+    /// named token), in source order.
+    ///
+    /// # Default
+    /// Empty. Parsers that can walk siblings natively should override this.
+    fn trivia_before(&self) -> Vec<Box<dyn ParseNode + '_>> {
+        Vec::new()
+    }
+
+    /// Descend from this node to the leaf (or pair of adjacent leaves)
+    /// covering `offset`, the way rust-analyzer's `find_leaf_at_offset`
+    /// does. Honors half-open-ish boundaries: a child is a candidate if
+    /// `offset` falls anywhere in its closed `[start_byte, end_byte]` range,
+    /// so an offset sitting exactly between two adjacent, non-empty leaves
+    /// matches both and is reported as `Between` rather than picked
+    /// arbitrarily. Zero-width children never match, so they can't swallow
+    /// a boundary offset that rightfully belongs to their neighbors.
+    fn leaf_at_offset(&self, offset: usize) -> LeafAtOffset<'_> {
+        if offset < self.start_byte() || offset > self.end_byte() {
+            return LeafAtOffset::None;
+        }
+
+        let covers = |node: &dyn ParseNode| {
+            node.start_byte() != node.end_byte()
+                && node.start_byte() <= offset
+                && offset <= node.end_byte()
+        };
+
+        let mut matches = (0..self.child_count())
+            .filter_map(|i| self.child(i))
+            .filter(|child| covers(child.as_ref()));
+
+        let Some(left) = matches.next() else {
+            return LeafAtOffset::Single(self.boxed());
+        };
+        let right = matches.next();
+        debug_assert!(
+            matches.next().is_none(),
+            "at most two adjacent children can share a boundary offset"
+        );
+
+        match right {
+            None => left.leaf_at_offset(offset),
+            Some(right) => match (left.leaf_at_offset(offset), right.leaf_at_offset(offset)) {
+                (LeafAtOffset::Single(left), LeafAtOffset::Single(right)) => {
+                    LeafAtOffset::Between(left, right)
+                }
+                // A child that isn't itself a leaf can still only contain
+                // `offset` on one side of its own boundary; the Between
+                // case is only possible once both sides bottom out.
+                (leaf, _) => leaf,
+            },
+        }
+    }
+
+    /// Find the smallest node covering `offset`, resolving a boundary
+    /// `Between` two leaves by walking `parent()` up from one side until an
+    /// ancestor spans both. Returns `None` when `offset` is outside this
+    /// node's span.
+    fn node_at_offset(&self, offset: usize) -> Option<Box<dyn ParseNode + '_>> {
+        match self.leaf_at_offset(offset) {
+            LeafAtOffset::None => None,
+            LeafAtOffset::Single(node) => Some(node),
+            LeafAtOffset::Between(left, right) => {
+                let mut ancestors = vec![left];
+                loop {
+                    let reached =
+                        ancestors.last().expect("just pushed").end_byte() >= right.end_byte();
+                    if reached {
+                        break;
+                    }
+                    let parent = ancestors.last().expect("just pushed").parent()?;
+                    ancestors.push(parent);
+                }
+                ancestors.pop()
+            }
+        }
+    }
+
+    /// Iterate this node's direct children, in order.
+    fn children_iter(&self) -> Box<dyn Iterator<Item = Box<dyn ParseNode + '_>> + '_> {
+        Box::new((0..self.child_count()).filter_map(move |i| self.child(i)))
+    }
+
+    /// Preorder DFS over this node's subtree, starting with this node itself.
+    fn descendants(&self) -> Box<dyn Iterator<Item = Box<dyn ParseNode + '_>> + '_> {
+        fn walk<'a>(node: Box<dyn ParseNode + 'a>, out: &mut Vec<Box<dyn ParseNode + 'a>>) {
+            out.push(node.boxed());
+            for i in 0..node.child_count() {
+                if let Some(child) = node.child(i) {
+                    walk(child, out);
+                }
+            }
+        }
+        let mut nodes = Vec::new();
+        walk(self.boxed(), &mut nodes);
+        Box::new(nodes.into_iter())
+    }
+
+    /// Walk `parent()` up from this node to the root, starting with this
+    /// node itself.
+    fn ancestors(&self) -> Box<dyn Iterator<Item = Box<dyn ParseNode + '_>> + '_> {
+        let mut nodes = vec![self.boxed()];
+        while let Some(parent) = nodes.last().expect("just pushed").parent() {
+            nodes.push(parent);
+        }
+        Box::new(nodes.into_iter())
+    }
+
     /// Debug representation of this node
     fn debug_info(&self) -> String;
 
@@ -243,6 +457,51 @@ impl<'tree> ParseNode for TreeSitterParseNode<'tree> {
             .map(|parent| Box::new(TreeSitterParseNode::new(parent)) as Box<dyn ParseNode + '_>)
     }
 
+    fn boxed(&self) -> Box<dyn ParseNode + '_> {
+        Box::new(TreeSitterParseNode::new(self.node))
+    }
+
+    fn tokens(&self) -> Box<dyn Iterator<Item = Box<dyn ParseNode + '_>> + '_> {
+        let mut leaves = Vec::new();
+        collect_leaves(self.node, &mut leaves);
+        Box::new(leaves.into_iter())
+    }
+
+    fn trivia_before(&self) -> Vec<Box<dyn ParseNode + '_>> {
+        // Same parent-cursor walk as `field_id`, but collecting the run of
+        // extra/anonymous siblings immediately before this node instead of
+        // this node's own field ID.
+        let Some(parent) = self.node.parent() else {
+            return Vec::new();
+        };
+        let mut cursor = parent.walk();
+        if !cursor.goto_first_child() {
+            return Vec::new();
+        }
+
+        let mut preceding = Vec::new();
+        loop {
+            if cursor.node().id() == self.node.id() {
+                break;
+            }
+            preceding.push(cursor.node());
+            if !cursor.goto_next_sibling() {
+                break;
+            }
+        }
+
+        let mut trivia = Vec::new();
+        while let Some(sibling) = preceding.pop() {
+            if sibling.is_extra() || !sibling.is_named() {
+                trivia.push(Box::new(TreeSitterParseNode::new(sibling)) as Box<dyn ParseNode + '_>);
+            } else {
+                break;
+            }
+        }
+        trivia.reverse();
+        trivia
+    }
+
     fn debug_info(&self) -> String {
         format!(
             "TreeSitterNode(kind: {}, kind_id: {}, bytes: {}..{})",
@@ -254,20 +513,173 @@ impl<'tree> ParseNode for TreeSitterParseNode<'tree> {
     }
 }
 
+/// Pre-order collection of `node`'s leaf descendants (`child_count() == 0`),
+/// including `is_extra()` trivia, used by `TreeSitterParseNode::tokens`.
+fn collect_leaves<'tree>(
+    node: ::tree_sitter::Node<'tree>,
+    out: &mut Vec<Box<dyn ParseNode + 'tree>>,
+) {
+    if node.child_count() == 0 {
+        out.push(Box::new(TreeSitterParseNode::new(node)));
+        return;
+    }
+
+    let mut cursor = node.walk();
+    if cursor.goto_first_child() {
+        loop {
+            collect_leaves(cursor.node(), out);
+            if !cursor.goto_next_sibling() {
+                break;
+            }
+        }
+    }
+}
+
+/// A recovered syntax problem inside a parsed tree, expressed as the byte
+/// range it covers and what kind of problem tree-sitter found there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyntaxError {
+    pub range: (usize, usize),
+    pub kind: ErrorKind,
+}
+
+/// Kind of problem a [`SyntaxError`] describes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// An `ERROR` node: the parser couldn't make sense of this span at all.
+    Unexpected,
+    /// A `MISSING` node: the grammar expected a token here but none was present.
+    Missing,
+    /// A parse that stopped short without ever producing `Unexpected`/`Missing`
+    /// nodes (e.g. an incremental reparse abandoned partway through). Not
+    /// produced by [`collect_syntax_errors`]; reserved for other sources of
+    /// `Parse` results to report this case.
+    Incomplete,
+}
+
+/// A parsed tree together with any syntax errors recovered while parsing it.
+///
+/// Mirrors rust-analyzer's `Parse<T>`: the tree is always handed back, even
+/// over broken input, so callers can decide for themselves whether `errors`
+/// is acceptable for their use case rather than losing the tree outright.
+pub struct Parse {
+    pub tree: Box<dyn ParseTree>,
+    pub errors: Vec<SyntaxError>,
+    /// Lints from [`LanguageTrait::validate`], filled in by the caller once
+    /// it knows which language parsed `tree` - empty until then.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Parse {
+    /// Wrap `tree`, collecting its syntax errors via [`collect_syntax_errors`].
+    pub fn new(tree: Box<dyn ParseTree>) -> Self {
+        let errors = collect_syntax_errors(tree.as_ref());
+        Self {
+            tree,
+            errors,
+            diagnostics: Vec::new(),
+        }
+    }
+}
+
+/// Walk `tree` once via [`ParseTree::root_node`], recording a [`SyntaxError`]
+/// for every node where `is_error()` or `is_missing()` is true.
+pub fn collect_syntax_errors(tree: &dyn ParseTree) -> Vec<SyntaxError> {
+    let mut errors = Vec::new();
+    if let Some(root) = tree.root_node() {
+        collect_syntax_errors_from(root.as_ref(), &mut errors);
+    }
+    errors
+}
+
+fn collect_syntax_errors_from(node: &dyn ParseNode, errors: &mut Vec<SyntaxError>) {
+    let range = (node.start_byte(), node.end_byte());
+    if node.is_error() {
+        errors.push(SyntaxError {
+            range,
+            kind: ErrorKind::Unexpected,
+        });
+    } else if node.is_missing() {
+        errors.push(SyntaxError {
+            range,
+            kind: ErrorKind::Missing,
+        });
+    }
+    for i in 0..node.child_count() {
+        if let Some(child) = node.child(i) {
+            collect_syntax_errors_from(child.as_ref(), errors);
+        }
+    }
+}
+
+/// A post-parse diagnostic, e.g. a malformed escape sequence inside a string
+/// literal, that the grammar itself has no way to reject.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub range: (usize, usize),
+    pub severity: Severity,
+    pub message: String,
+}
+
+/// Severity of a [`Diagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+    Info,
+}
+
 /// Scopes trait defining language-specific AST handling.
 pub trait LanguageTrait {
-    /// Parse source code and return a generic parse tree.
+    /// Parse source code and return a generic parse tree plus any recovered
+    /// syntax errors.
     ///
     /// # Returns
-    /// A boxed `ParseTree` trait object, allowing multiple parser implementations.
+    /// A [`Parse`] wrapping a boxed `ParseTree` trait object, allowing
+    /// multiple parser implementations.
     ///
     /// # Default
     /// Returns `None` by default. Languages should implement custom parsing
     /// either by overriding this method or by using `LanguageTraitExt`.
-    fn parse(_text: impl AsRef<[u8]>) -> Option<Box<dyn ParseTree>> {
+    fn parse(_text: impl AsRef<[u8]>) -> Option<Parse> {
         None
     }
 
+    /// Incrementally reparse `text`, reusing the unaffected subtrees of
+    /// `old` wherever `edits` didn't touch them.
+    ///
+    /// # Default
+    /// Works for any `old` backed by [`TreeSitterParseTree`]: applies each
+    /// edit, recovers the grammar from the old tree itself via
+    /// `tree_sitter::Tree::language`, and reparses with the edited tree
+    /// passed back in so tree-sitter can reuse unchanged subtrees. Returns
+    /// `None` if `old` isn't a `TreeSitterParseTree` or parsing fails.
+    fn reparse(
+        old: &dyn ParseTree,
+        text: impl AsRef<[u8]>,
+        edits: &[Edit],
+    ) -> Option<Box<dyn ParseTree>> {
+        let old_tree = old.as_any().downcast_ref::<TreeSitterParseTree>()?;
+        let mut edited = old_tree.clone();
+        for edit in edits {
+            edited.apply_edit(edit);
+        }
+
+        let mut parser = ::tree_sitter::Parser::new();
+        parser.set_language(&edited.tree.language()).ok()?;
+        let bytes = text.as_ref();
+        let new_tree = parser.parse(bytes, Some(&edited.tree))?;
+        Some(Box::new(TreeSitterParseTree::new(new_tree, bytes)) as Box<dyn ParseTree>)
+    }
+
+    /// Run post-parse validation over `tree`, appending any diagnostics found
+    /// to `sink`.
+    ///
+    /// # Default
+    /// No-op. Languages opt in by overriding `LanguageTraitExt::validate_impl`,
+    /// matching how custom `parse_impl` plugs into `parse`.
+    fn validate(_tree: &dyn ParseTree, _source: &[u8], _sink: &mut Vec<Diagnostic>) {}
+
     /// Map a token kind ID to its corresponding HIR kind.
     fn hir_kind(kind_id: u16) -> HirKind;
 
@@ -312,6 +724,15 @@ pub trait LanguageTraitExt: LanguageTrait {
     /// Languages should implement this method instead of overriding `LanguageTrait::parse`.
     /// Return `None` to fall back to tree-sitter parsing (if available).
     fn parse_impl(text: impl AsRef<[u8]>) -> Option<Box<dyn ParseTree>>;
+
+    /// Custom post-parse validation for this language.
+    ///
+    /// Languages should implement this method instead of overriding
+    /// `LanguageTrait::validate`.
+    ///
+    /// # Default
+    /// No diagnostics.
+    fn validate_impl(_tree: &dyn ParseTree, _source: &[u8], _sink: &mut Vec<Diagnostic>) {}
 }
 
 #[allow(clippy::crate_in_macro_def)]
@@ -349,12 +770,24 @@ macro_rules! define_lang {
             // Language Trait Implementation
             // ============================================================
             impl $crate::lang_def::LanguageTrait for [<Lang $suffix>] {
-                /// Parse source code and return a generic parse tree.
+                /// Parse source code and return a generic parse tree plus any
+                /// recovered syntax errors.
                 ///
                 /// First tries the custom parse_impl from LanguageTraitExt.
                 /// If that returns None, falls back to tree-sitter parsing if available.
-                fn parse(text: impl AsRef<[u8]>) -> Option<Box<dyn $crate::lang_def::ParseTree>> {
+                fn parse(text: impl AsRef<[u8]>) -> Option<$crate::lang_def::Parse> {
                     <Self as $crate::lang_def::LanguageTraitExt>::parse_impl(text.as_ref())
+                        .map($crate::lang_def::Parse::new)
+                }
+
+                /// Run post-parse validation over `tree`, deferring to the
+                /// custom validate_impl from LanguageTraitExt.
+                fn validate(
+                    tree: &dyn $crate::lang_def::ParseTree,
+                    source: &[u8],
+                    sink: &mut Vec<$crate::lang_def::Diagnostic>,
+                ) {
+                    <Self as $crate::lang_def::LanguageTraitExt>::validate_impl(tree, source, sink)
                 }
 
                 /// Return the list of supported file extensions for this language
@@ -486,4 +919,334 @@ macro_rules! define_lang {
     // ================================================================
     (@unwrap_block $block:expr) => { $block };
     (@unwrap_block) => { $crate::graph_builder::BlockKind::Undefined };
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Flat arena backing [`FixtureNode`]s, so `parent()` can be a real
+    /// backlink instead of the trait's default `None` - `tests::simple_lang`
+    /// doesn't implement `parent`/`tokens`/`trivia_before`, so it can't
+    /// exercise the default methods that depend on them.
+    struct FixtureTree {
+        nodes: Vec<FixtureNode>,
+    }
+
+    struct FixtureNode {
+        kind_id: u16,
+        start: usize,
+        end: usize,
+        is_extra: bool,
+        parent: Option<usize>,
+        children: Vec<usize>,
+    }
+
+    struct FixtureParseNode<'a> {
+        tree: &'a FixtureTree,
+        index: usize,
+    }
+
+    impl FixtureParseNode<'_> {
+        fn node(&self) -> &FixtureNode {
+            &self.tree.nodes[self.index]
+        }
+    }
+
+    impl<'a> ParseNode for FixtureParseNode<'a> {
+        fn kind_id(&self) -> u16 {
+            self.node().kind_id
+        }
+
+        fn start_byte(&self) -> usize {
+            self.node().start
+        }
+
+        fn end_byte(&self) -> usize {
+            self.node().end
+        }
+
+        fn child_count(&self) -> usize {
+            self.node().children.len()
+        }
+
+        fn child(&self, index: usize) -> Option<Box<dyn ParseNode + '_>> {
+            self.node().children.get(index).map(|&i| {
+                Box::new(FixtureParseNode {
+                    tree: self.tree,
+                    index: i,
+                }) as Box<dyn ParseNode + '_>
+            })
+        }
+
+        fn child_by_field_name(&self, _field_name: &str) -> Option<Box<dyn ParseNode + '_>> {
+            None
+        }
+
+        fn is_extra(&self) -> bool {
+            self.node().is_extra
+        }
+
+        fn parent(&self) -> Option<Box<dyn ParseNode + '_>> {
+            self.node().parent.map(|i| {
+                Box::new(FixtureParseNode {
+                    tree: self.tree,
+                    index: i,
+                }) as Box<dyn ParseNode + '_>
+            })
+        }
+
+        fn boxed(&self) -> Box<dyn ParseNode + '_> {
+            Box::new(FixtureParseNode {
+                tree: self.tree,
+                index: self.index,
+            })
+        }
+
+        /// Leaves in source order, including `is_extra` trivia, by reusing
+        /// the preorder walk from [`ParseNode::descendants`] and filtering
+        /// down to childless nodes.
+        fn tokens(&self) -> Box<dyn Iterator<Item = Box<dyn ParseNode + '_>> + '_> {
+            Box::new(self.descendants().filter(|n| n.child_count() == 0))
+        }
+
+        fn trivia_before(&self) -> Vec<Box<dyn ParseNode + '_>> {
+            let Some(parent_index) = self.node().parent else {
+                return Vec::new();
+            };
+            let siblings = &self.tree.nodes[parent_index].children;
+            let Some(position) = siblings.iter().position(|&i| i == self.index) else {
+                return Vec::new();
+            };
+
+            let mut trivia: Vec<Box<dyn ParseNode + '_>> = Vec::new();
+            for &sibling in siblings[..position].iter().rev() {
+                if !self.tree.nodes[sibling].is_extra {
+                    break;
+                }
+                trivia.push(Box::new(FixtureParseNode {
+                    tree: self.tree,
+                    index: sibling,
+                }));
+            }
+            trivia.reverse();
+            trivia
+        }
+
+        fn debug_info(&self) -> String {
+            format!(
+                "Fixture(kind: {}, {}..{})",
+                self.kind_id(),
+                self.start_byte(),
+                self.end_byte()
+            )
+        }
+    }
+
+    const KIND_ROOT: u16 = 100;
+    const KIND_KEYWORD: u16 = 1;
+    const KIND_IDENT: u16 = 2;
+    const KIND_GROUP: u16 = 200;
+    const KIND_TRIVIA: u16 = 0;
+
+    /// Builds the fixture for `"fn foo bar"`, structured as:
+    /// `root[kw("fn"), ws, group[ident("foo"), ws, ident("bar")]]` - a
+    /// keyword leaf, a trivia leaf, and a nested group so traversal tests can
+    /// tell direct children from deeper descendants.
+    fn build_fixture() -> FixtureTree {
+        FixtureTree {
+            nodes: vec![
+                FixtureNode {
+                    kind_id: KIND_ROOT,
+                    start: 0,
+                    end: 10,
+                    is_extra: false,
+                    parent: None,
+                    children: vec![1, 2, 3],
+                },
+                FixtureNode {
+                    kind_id: KIND_KEYWORD,
+                    start: 0,
+                    end: 2,
+                    is_extra: false,
+                    parent: Some(0),
+                    children: vec![],
+                },
+                FixtureNode {
+                    kind_id: KIND_TRIVIA,
+                    start: 2,
+                    end: 3,
+                    is_extra: true,
+                    parent: Some(0),
+                    children: vec![],
+                },
+                FixtureNode {
+                    kind_id: KIND_GROUP,
+                    start: 3,
+                    end: 10,
+                    is_extra: false,
+                    parent: Some(0),
+                    children: vec![4, 5, 6],
+                },
+                FixtureNode {
+                    kind_id: KIND_IDENT,
+                    start: 3,
+                    end: 6,
+                    is_extra: false,
+                    parent: Some(3),
+                    children: vec![],
+                },
+                FixtureNode {
+                    kind_id: KIND_TRIVIA,
+                    start: 6,
+                    end: 7,
+                    is_extra: true,
+                    parent: Some(3),
+                    children: vec![],
+                },
+                FixtureNode {
+                    kind_id: KIND_IDENT,
+                    start: 7,
+                    end: 10,
+                    is_extra: false,
+                    parent: Some(3),
+                    children: vec![],
+                },
+            ],
+        }
+    }
+
+    fn node(tree: &FixtureTree, index: usize) -> FixtureParseNode<'_> {
+        FixtureParseNode { tree, index }
+    }
+
+    const FIXTURE_SOURCE: &[u8] = b"fn foo bar";
+
+    #[test]
+    fn text_slices_the_exact_source_span() {
+        let tree = build_fixture();
+        // Group node spans "foo bar", skipping the leading "fn " entirely.
+        assert_eq!(node(&tree, 3).text(FIXTURE_SOURCE), "foo bar");
+        assert_eq!(node(&tree, 4).text(FIXTURE_SOURCE), "foo");
+    }
+
+    #[test]
+    fn tokens_yields_leaves_in_source_order_including_trivia() {
+        let tree = build_fixture();
+        let spans: Vec<(usize, usize)> = node(&tree, 0)
+            .tokens()
+            .map(|n| (n.start_byte(), n.end_byte()))
+            .collect();
+        assert_eq!(
+            spans,
+            vec![(0, 2), (2, 3), (3, 6), (6, 7), (7, 10)],
+            "tokens() should walk every leaf in source order, trivia included"
+        );
+    }
+
+    #[test]
+    fn trivia_before_stops_at_the_first_non_extra_sibling() {
+        let tree = build_fixture();
+        // The group's only preceding trivia is the "fn"/"foo bar" gap.
+        let group_trivia = node(&tree, 3).trivia_before();
+        assert_eq!(group_trivia.len(), 1);
+        assert_eq!(group_trivia[0].start_byte(), 2);
+
+        // "bar" is preceded by a trivia leaf, then the non-extra "foo".
+        let bar_trivia = node(&tree, 6).trivia_before();
+        assert_eq!(bar_trivia.len(), 1);
+        assert_eq!(bar_trivia[0].start_byte(), 6);
+
+        // "foo" has no preceding sibling at all within the group.
+        assert!(node(&tree, 4).trivia_before().is_empty());
+    }
+
+    #[test]
+    fn leaf_at_offset_resolves_a_single_interior_leaf() {
+        let tree = build_fixture();
+        // Strictly inside "foo" (3..6).
+        match node(&tree, 0).leaf_at_offset(4) {
+            LeafAtOffset::Single(leaf) => assert_eq!((leaf.start_byte(), leaf.end_byte()), (3, 6)),
+            _ => panic!("expected a single leaf"),
+        }
+    }
+
+    #[test]
+    fn leaf_at_offset_reports_between_at_a_shared_boundary() {
+        let tree = build_fixture();
+        // Offset 3 sits on the boundary between the trivia leaf (2..3) and
+        // the group, whose own first leaf ("foo", 3..6) starts exactly there.
+        match node(&tree, 0).leaf_at_offset(3) {
+            LeafAtOffset::Between(left, right) => {
+                assert_eq!((left.start_byte(), left.end_byte()), (2, 3));
+                assert_eq!((right.start_byte(), right.end_byte()), (3, 6));
+            }
+            _ => panic!("expected adjacent leaves sharing a boundary"),
+        }
+    }
+
+    #[test]
+    fn leaf_at_offset_is_none_outside_the_root_span() {
+        let tree = build_fixture();
+        assert!(matches!(node(&tree, 0).leaf_at_offset(11), LeafAtOffset::None));
+    }
+
+    #[test]
+    fn node_at_offset_climbs_to_the_smallest_covering_ancestor() {
+        let tree = build_fixture();
+        // At the 2/3 boundary the smallest node spanning both the trivia
+        // leaf and "foo" is the root itself - the group starts at 3, so it
+        // doesn't reach back far enough to cover the trivia leaf.
+        let covering = node(&tree, 0).node_at_offset(3).expect("within span");
+        assert_eq!((covering.start_byte(), covering.end_byte()), (0, 10));
+    }
+
+    #[test]
+    fn node_at_offset_returns_the_leaf_itself_when_unambiguous() {
+        let tree = build_fixture();
+        let covering = node(&tree, 0).node_at_offset(4).expect("within span");
+        assert_eq!((covering.start_byte(), covering.end_byte()), (3, 6));
+    }
+
+    #[test]
+    fn node_at_offset_is_none_outside_the_root_span() {
+        let tree = build_fixture();
+        assert!(node(&tree, 0).node_at_offset(11).is_none());
+    }
+
+    #[test]
+    fn children_iter_yields_only_direct_children() {
+        let tree = build_fixture();
+        let spans: Vec<(usize, usize)> = node(&tree, 0)
+            .children_iter()
+            .map(|n| (n.start_byte(), n.end_byte()))
+            .collect();
+        // The group's own children ("foo"/trivia/"bar") must not appear here.
+        assert_eq!(spans, vec![(0, 2), (2, 3), (3, 10)]);
+    }
+
+    #[test]
+    fn descendants_is_a_self_inclusive_preorder_walk() {
+        let tree = build_fixture();
+        let spans: Vec<(usize, usize)> = node(&tree, 0)
+            .descendants()
+            .map(|n| (n.start_byte(), n.end_byte()))
+            .collect();
+        assert_eq!(
+            spans,
+            vec![(0, 10), (0, 2), (2, 3), (3, 10), (3, 6), (6, 7), (7, 10)],
+            "descendants() should start with self, then preorder the rest"
+        );
+    }
+
+    #[test]
+    fn ancestors_is_a_self_inclusive_walk_to_the_root() {
+        let tree = build_fixture();
+        // Start from "bar" (index 6), nested two levels under the root.
+        let spans: Vec<(usize, usize)> = node(&tree, 6)
+            .ancestors()
+            .map(|n| (n.start_byte(), n.end_byte()))
+            .collect();
+        assert_eq!(spans, vec![(7, 10), (3, 10), (0, 10)]);
+    }
+}
@@ -7,7 +7,7 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::lang_def::{LanguageTraitImpl, ParseTree};
+use crate::lang_def::{LanguageTraitImpl, Parse};
 
 /// Object-safe language handler trait.
 /// This wraps static `LanguageTrait` methods into dynamic dispatch.
@@ -26,8 +26,9 @@ pub trait LanguageHandler: Send + Sync {
         self.extensions().contains(&ext)
     }
 
-    /// Parse source code and return a generic parse tree
-    fn parse(&self, text: &[u8]) -> Option<Box<dyn ParseTree>>;
+    /// Parse source code and return a generic parse tree plus any recovered
+    /// syntax errors
+    fn parse(&self, text: &[u8]) -> Option<Parse>;
 }
 
 /// A language handler implementation that wraps a LanguageTraitImpl.
@@ -65,7 +66,7 @@ where
         L::manifest_name()
     }
 
-    fn parse(&self, text: &[u8]) -> Option<Box<dyn ParseTree>> {
+    fn parse(&self, text: &[u8]) -> Option<Parse> {
         L::parse(text)
     }
 }
@@ -186,7 +187,7 @@ mod tests {
             "mock.toml"
         }
 
-        fn parse(&self, _text: &[u8]) -> Option<Box<dyn ParseTree>> {
+        fn parse(&self, _text: &[u8]) -> Option<Parse> {
             None
         }
     }
@@ -3,9 +3,11 @@ pub mod block_rel;
 pub mod bump;
 pub mod context;
 pub mod file;
+pub mod fuzzy;
 pub mod graph;
 pub mod graph_builder;
 pub mod graph_render;
+pub mod incremental;
 pub mod interner;
 pub mod ir;
 pub mod ir_builder;
@@ -16,6 +18,8 @@ pub mod printer;
 pub mod query;
 pub mod scope;
 pub mod symbol;
+#[cfg(test)]
+pub mod tests;
 pub mod visit;
 
 pub type DynError = Box<dyn std::error::Error + Send + Sync>;
@@ -11,10 +11,21 @@
 //! # Philosophy
 //!
 //! Packages (Cargo.toml, package.json) are the **real semantic boundaries** - developers
-//! explicitly created them. We respect these as-is.
+//! explicitly created them. We respect these as-is. Each manifest format is handled by a
+//! [`ManifestParser`], so adding a new ecosystem doesn't mean growing an `if`/`else` ladder.
+//!
+//! Cargo workspaces get a little more care: a `Cargo.toml` with only `[workspace]` (no
+//! `[package]`) is a virtual manifest and never becomes a package itself, its `members`/
+//! `exclude` globs are expanded so member crates are registered even before we've seen any
+//! of their files, and `field.workspace = true` is resolved against the workspace root's
+//! `[workspace.package]` table.
 //!
 //! For modules, we use a per-file bottom-up approach: walk up from each file toward the
 //! package root, finding the first directory that represents a meaningful grouping.
+//! Developers can drop an `.llmcc.toml` in any directory to force or forbid a module
+//! boundary there, rename the inferred module, or tune the significance/dominance
+//! thresholds below; settings cascade from package root downward, with a child
+//! directory's file overlaying (not replacing) what its parents set.
 //!
 //! # Algorithm: Per-File Bottom-Up Module Detection
 //!
@@ -32,6 +43,9 @@
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
+use ignore::WalkBuilder;
+use toml::Table;
+
 // ============================================================================
 // Public Types
 // ============================================================================
@@ -72,6 +86,11 @@ pub struct UnitMeta {
     pub module_root: Option<PathBuf>,
     pub file_name: Option<String>,
     pub file_path: Option<PathBuf>,
+    /// Package's Rust edition, e.g. `"2021"` (Cargo packages only).
+    pub edition: Option<String>,
+    /// Whether this package is a member of a Cargo workspace, as opposed to
+    /// a standalone crate or a path/vendored dependency.
+    pub is_workspace_member: bool,
 }
 
 impl UnitMeta {
@@ -146,6 +165,315 @@ struct PackageInfo {
     root: PathBuf,
     trie: TrieNode,
     total_files: usize,
+    edition: Option<String>,
+    is_workspace_member: bool,
+}
+
+// ============================================================================
+// Manifest Parsers
+// ============================================================================
+
+/// Package metadata extracted from a manifest file.
+#[derive(Debug, Clone, Default)]
+struct ManifestInfo {
+    /// `None` for a Cargo virtual manifest (`[workspace]` with no `[package]`),
+    /// which declares members but isn't itself a package.
+    name: Option<String>,
+    /// Workspace member glob patterns, if this manifest declares a workspace
+    /// (currently only `Cargo.toml`'s `[workspace].members`).
+    members: Vec<String>,
+}
+
+/// Extracts a [`ManifestInfo`] from one kind of package manifest file.
+///
+/// Implement this to teach [`UnitMetaBuilder`] about a new ecosystem instead
+/// of growing a hardcoded manifest-format ladder.
+trait ManifestParser {
+    /// Whether this parser handles a manifest file named `name`.
+    fn matches(&self, name: &str) -> bool;
+
+    /// Parse a manifest file's `content` into package metadata.
+    fn parse(&self, content: &str) -> Option<ManifestInfo>;
+}
+
+/// The built-in parsers; the first whose `matches` accepts the manifest file
+/// name parses it.
+fn manifest_parsers() -> Vec<Box<dyn ManifestParser>> {
+    vec![
+        Box::new(CargoManifestParser),
+        Box::new(PackageJsonParser),
+        Box::new(PyProjectTomlParser),
+        Box::new(GoModParser),
+        Box::new(SetupCfgParser),
+        Box::new(ComposerJsonParser),
+    ]
+}
+
+struct CargoManifestParser;
+
+impl ManifestParser for CargoManifestParser {
+    fn matches(&self, name: &str) -> bool {
+        name == "Cargo.toml"
+    }
+
+    fn parse(&self, content: &str) -> Option<ManifestInfo> {
+        let table = content.parse::<Table>().ok()?;
+        let name = table
+            .get("package")
+            .and_then(|p| p.get("name"))
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string());
+
+        let members = table
+            .get("workspace")
+            .and_then(|w| w.get("members"))
+            .and_then(|m| m.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.as_str().map(String::from))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // A virtual manifest (`[workspace]`, no `[package]`) has no name of
+        // its own but still needs its members surfaced.
+        if name.is_none() && members.is_empty() {
+            return None;
+        }
+
+        Some(ManifestInfo { name, members })
+    }
+}
+
+struct PackageJsonParser;
+
+impl ManifestParser for PackageJsonParser {
+    fn matches(&self, name: &str) -> bool {
+        name == "package.json"
+    }
+
+    fn parse(&self, content: &str) -> Option<ManifestInfo> {
+        let value: serde_json::Value = serde_json::from_str(content).ok()?;
+        let name = value.get("name")?.as_str()?;
+        Some(ManifestInfo {
+            name: Some(name.replace('@', "").replace('/', "_")),
+            members: Vec::new(),
+        })
+    }
+}
+
+struct PyProjectTomlParser;
+
+impl ManifestParser for PyProjectTomlParser {
+    fn matches(&self, name: &str) -> bool {
+        name == "pyproject.toml"
+    }
+
+    fn parse(&self, content: &str) -> Option<ManifestInfo> {
+        let table = content.parse::<Table>().ok()?;
+
+        // PEP 621
+        if let Some(project) = table.get("project")
+            && let Some(name) = project.get("name")
+            && let Some(name_str) = name.as_str()
+        {
+            return Some(ManifestInfo {
+                name: Some(name_str.to_string()),
+                members: Vec::new(),
+            });
+        }
+
+        // Legacy Poetry
+        if let Some(tool) = table.get("tool")
+            && let Some(poetry) = tool.get("poetry")
+            && let Some(name) = poetry.get("name")
+            && let Some(name_str) = name.as_str()
+        {
+            return Some(ManifestInfo {
+                name: Some(name_str.to_string()),
+                members: Vec::new(),
+            });
+        }
+
+        None
+    }
+}
+
+struct GoModParser;
+
+impl ManifestParser for GoModParser {
+    fn matches(&self, name: &str) -> bool {
+        name == "go.mod"
+    }
+
+    fn parse(&self, content: &str) -> Option<ManifestInfo> {
+        let module_path = content
+            .lines()
+            .find_map(|line| line.trim().strip_prefix("module "))?
+            .trim();
+
+        // "github.com/foo/bar" -> "bar"
+        let name = module_path.rsplit('/').next().unwrap_or(module_path);
+        Some(ManifestInfo {
+            name: Some(name.to_string()),
+            members: Vec::new(),
+        })
+    }
+}
+
+struct SetupCfgParser;
+
+impl ManifestParser for SetupCfgParser {
+    fn matches(&self, name: &str) -> bool {
+        name == "setup.cfg"
+    }
+
+    fn parse(&self, content: &str) -> Option<ManifestInfo> {
+        let mut in_metadata = false;
+        for line in content.lines() {
+            let line = line.trim();
+            if line == "[metadata]" {
+                in_metadata = true;
+            } else if line.starts_with('[') {
+                in_metadata = false;
+            } else if in_metadata && line.starts_with("name") {
+                let name = line.split('=').nth(1)?.trim().to_string();
+                return Some(ManifestInfo {
+                    name: Some(name),
+                    members: Vec::new(),
+                });
+            }
+        }
+        None
+    }
+}
+
+struct ComposerJsonParser;
+
+impl ManifestParser for ComposerJsonParser {
+    fn matches(&self, name: &str) -> bool {
+        name == "composer.json"
+    }
+
+    fn parse(&self, content: &str) -> Option<ManifestInfo> {
+        let value: serde_json::Value = serde_json::from_str(content).ok()?;
+        let name = value.get("name")?.as_str()?;
+        Some(ManifestInfo {
+            name: Some(name.replace('/', "_")),
+            members: Vec::new(),
+        })
+    }
+}
+
+// ============================================================================
+// Discovery Patterns
+// ============================================================================
+
+/// One `include`/`exclude` pattern split into its non-glob base directory
+/// and the glob pattern relative to it, so matching only has to run against
+/// patterns whose base could actually contain the path being checked.
+struct PatternMatcher {
+    base: PathBuf,
+    pattern: String,
+}
+
+impl PatternMatcher {
+    /// Parse `raw` (relative to `project_root`) by taking the leading
+    /// non-glob path segments as the base, e.g. `"src/**/*.rs"` splits into
+    /// base `"src"` and pattern `"**/*.rs"`.
+    fn parse(project_root: &Path, raw: &str) -> Self {
+        let segments: Vec<&str> = raw.split('/').collect();
+        let base_len = segments
+            .iter()
+            .take_while(|seg| !seg.contains('*') && !seg.contains('?'))
+            .count();
+
+        Self {
+            base: project_root.join(segments[..base_len].join("/")),
+            pattern: segments[base_len..].join("/"),
+        }
+    }
+
+    /// Cheap pre-filter: whether `path` is anywhere on the same branch as
+    /// this pattern's base (ancestor or descendant), i.e. whether running
+    /// the more expensive glob match against it is even worthwhile.
+    fn could_match(&self, path: &Path) -> bool {
+        path.starts_with(&self.base) || self.base.starts_with(path)
+    }
+
+    fn matches(&self, path: &Path) -> bool {
+        let Ok(rel) = path.strip_prefix(&self.base) else {
+            return false;
+        };
+        if self.pattern.is_empty() {
+            return true;
+        }
+        glob_match(self.pattern.as_bytes(), rel.to_string_lossy().as_bytes())
+    }
+}
+
+/// Minimal glob matcher supporting `*` (any run of characters except `/`),
+/// `**` (any run of characters, including `/`), `?` (any single non-`/`
+/// character), and literals.
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern {
+        [] => text.is_empty(),
+        [b'*', b'*', rest @ ..] => (0..=text.len()).any(|i| glob_match(rest, &text[i..])),
+        [b'*', rest @ ..] => (0..=text.len())
+            .take_while(|&i| i == 0 || text[i - 1] != b'/')
+            .any(|i| glob_match(rest, &text[i..])),
+        [b'?', rest @ ..] => !text.is_empty() && text[0] != b'/' && glob_match(rest, &text[1..]),
+        [c, rest @ ..] => text.first() == Some(c) && glob_match(rest, &text[1..]),
+    }
+}
+
+// ============================================================================
+// Module Overrides
+// ============================================================================
+
+/// A directory's own, unmerged `.llmcc.toml` settings. `None` fields mean
+/// "not opinionated here" and are inherited from the nearest ancestor that
+/// does set them.
+#[derive(Debug, Clone, Default)]
+struct ModuleOverride {
+    /// Force (`true`) or forbid (`false`) this directory being a module
+    /// boundary, instead of guessing via the trie heuristic.
+    module_boundary: Option<bool>,
+    /// Override the inferred module name for this directory's boundary.
+    module_name: Option<String>,
+    /// Override the significance ratio (fraction of the package's files a
+    /// sibling group must reach, default `0.05`) for this subtree.
+    significance: Option<f64>,
+    /// Override the dominance ceiling (default `0.80`) for this subtree.
+    dominance: Option<f64>,
+}
+
+impl ModuleOverride {
+    fn from_table(table: &Table) -> Self {
+        Self {
+            module_boundary: table.get("module_boundary").and_then(|v| v.as_bool()),
+            module_name: table
+                .get("module_name")
+                .and_then(|v| v.as_str())
+                .map(String::from),
+            significance: table.get("significance").and_then(|v| v.as_float()),
+            dominance: table.get("dominance").and_then(|v| v.as_float()),
+        }
+    }
+
+    /// Overlay `child`'s explicit fields on top of `self` (the parent); a
+    /// field `child` doesn't set keeps falling back to `self`.
+    fn overlay(&self, child: &Self) -> Self {
+        Self {
+            module_boundary: child.module_boundary.or(self.module_boundary),
+            module_name: child
+                .module_name
+                .clone()
+                .or_else(|| self.module_name.clone()),
+            significance: child.significance.or(self.significance),
+            dominance: child.dominance.or(self.dominance),
+        }
+    }
 }
 
 // ============================================================================
@@ -198,6 +526,88 @@ impl UnitMetaBuilder {
         detector
     }
 
+    /// Walk `project_root` itself and build a detector from what's found,
+    /// instead of requiring the caller to pre-collect a file list.
+    ///
+    /// `include`/`exclude` are glob patterns relative to `project_root` (e.g.
+    /// `"src/**/*.rs"`); a file must match at least one `include` pattern to
+    /// be considered (an empty `include` list matches everything with one of
+    /// `L`'s supported extensions). `.gitignore` rules are honored
+    /// automatically. Excludes are checked against every directory *during*
+    /// the walk so a matching directory is pruned before its subtree is ever
+    /// visited, rather than discovering it and filtering it out afterward.
+    pub fn discover<L: crate::lang_def::LanguageTrait>(
+        project_root: &Path,
+        include: &[String],
+        exclude: &[String],
+    ) -> Self {
+        let files = Self::walk_files(project_root, include, exclude, L::supported_extensions());
+        Self::with_lang_config(
+            &files,
+            project_root,
+            L::manifest_name(),
+            L::container_dirs(),
+        )
+    }
+
+    /// Streaming directory walk feeding matched files straight into a
+    /// `Vec`, pruning excluded subtrees as they're encountered instead of
+    /// expanding every exclude glob into a file set up front.
+    fn walk_files(
+        project_root: &Path,
+        include: &[String],
+        exclude: &[String],
+        extensions: &[&str],
+    ) -> Vec<PathBuf> {
+        let includes: Vec<PatternMatcher> = include
+            .iter()
+            .map(|raw| PatternMatcher::parse(project_root, raw))
+            .collect();
+        let excludes: Vec<PatternMatcher> = exclude
+            .iter()
+            .map(|raw| PatternMatcher::parse(project_root, raw))
+            .collect();
+
+        let mut builder = WalkBuilder::new(project_root);
+        builder.standard_filters(true).follow_links(false);
+        builder.filter_entry(move |entry| {
+            if entry.depth() == 0 {
+                return true;
+            }
+            let path = entry.path();
+            !excludes
+                .iter()
+                .any(|pat| pat.could_match(path) && pat.matches(path))
+        });
+
+        let mut files = Vec::new();
+        for entry in builder.build() {
+            let Ok(entry) = entry else { continue };
+            if !entry.file_type().map(|ft| ft.is_file()).unwrap_or(false) {
+                continue;
+            }
+
+            let path = entry.path();
+            let Some(ext) = path.extension().and_then(|e| e.to_str()) else {
+                continue;
+            };
+            if !extensions.contains(&ext) {
+                continue;
+            }
+
+            if !includes.is_empty()
+                && !includes
+                    .iter()
+                    .any(|pat| pat.could_match(path) && pat.matches(path))
+            {
+                continue;
+            }
+
+            files.push(path.to_path_buf());
+        }
+        files
+    }
+
     fn is_container(&self, name: &str) -> bool {
         self.container_dirs.contains(&name)
     }
@@ -213,6 +623,7 @@ impl UnitMetaBuilder {
 
     fn detect_packages(&mut self, files: &[PathBuf]) {
         let mut seen = std::collections::HashSet::new();
+        let mut workspace_roots = std::collections::HashSet::new();
 
         for file in files {
             let mut dir = file.parent();
@@ -225,20 +636,47 @@ impl UnitMetaBuilder {
                 if manifest.exists() && !seen.contains(current) {
                     seen.insert(current.to_path_buf());
 
-                    if let Some(name) = self.parse_manifest_name(current) {
+                    if self.manifest_name == "Cargo.toml" {
+                        self.register_cargo_package(current, &mut workspace_roots);
+                    } else if let Some(name) = self.parse_manifest_name(current) {
                         self.packages.push(PackageInfo {
                             name,
                             root: current.to_path_buf(),
                             trie: TrieNode::new(),
                             total_files: 0,
+                            edition: None,
+                            is_workspace_member: false,
                         });
+                        // Non-Cargo manifests have no workspace concept, so
+                        // stop at the nearest one as before.
+                        break;
                     }
-                    break;
                 }
                 dir = current.parent();
             }
         }
 
+        // `[workspace].members`/`exclude` globs enroll crates top-down, so a
+        // member with no file of its own in `files` (yet) still gets a package.
+        for ws_root in &workspace_roots {
+            self.enroll_workspace_members(ws_root, &mut seen);
+        }
+
+        // A member crate's own `Cargo.toml` is usually the nearest manifest
+        // to one of its files, so it gets registered via `register_cargo_package`
+        // before the climb above ever reaches the workspace root - `enroll_workspace_members`
+        // then skips it (it's already `seen`) without ever setting
+        // `is_workspace_member`. Fix up membership here, now that every
+        // workspace root in this project has been discovered.
+        for pkg in &mut self.packages {
+            if workspace_roots
+                .iter()
+                .any(|root| pkg.root != *root && pkg.root.starts_with(root))
+            {
+                pkg.is_workspace_member = true;
+            }
+        }
+
         // Sort by depth (deepest first) for nested package detection
         self.packages.sort_by(|a, b| {
             b.root
@@ -251,38 +689,181 @@ impl UnitMetaBuilder {
     fn parse_manifest_name(&self, dir: &Path) -> Option<String> {
         let content = std::fs::read_to_string(dir.join(self.manifest_name)).ok()?;
 
-        // Try JSON format first (package.json)
-        if self.manifest_name == "package.json" {
-            // Parse "name": "value" from JSON
-            let name_pos = content.find("\"name\"")?;
-            let after_name = &content[name_pos + 6..];
-            let colon_pos = after_name.find(':')?;
-            let after_colon = &after_name[colon_pos + 1..];
-            let start_quote = after_colon.find('"')?;
-            let value_start = &after_colon[start_quote + 1..];
-            let end_quote = value_start.find('"')?;
-            let value = &value_start[..end_quote];
-            Some(value.replace('@', "").replace('/', "_"))
-        } else if self.manifest_name == "Cargo.toml" {
-            // Parse TOML format (Cargo.toml)
-            let mut in_package = false;
-            for line in content.lines() {
-                let line = line.trim();
-                if line == "[package]" {
-                    in_package = true;
-                } else if line.starts_with('[') {
-                    in_package = false;
-                } else if in_package && line.starts_with("name") {
-                    return line
-                        .find('=')
-                        .map(|pos| line[pos + 1..].trim().trim_matches('"').to_string());
+        if let Some(parser) = manifest_parsers()
+            .into_iter()
+            .find(|parser| parser.matches(self.manifest_name))
+        {
+            return parser.parse(&content).and_then(|info| info.name);
+        }
+
+        // Unknown manifest format - use directory name
+        dir.file_name()
+            .and_then(|n| n.to_str())
+            .map(|s| s.to_string())
+    }
+
+    /// Register the Cargo package at `dir`, or if its `Cargo.toml` is a
+    /// virtual manifest (`[workspace]` with no `[package]`), record it in
+    /// `workspace_roots` so `enroll_workspace_members` can expand it instead.
+    fn register_cargo_package(
+        &mut self,
+        dir: &Path,
+        workspace_roots: &mut std::collections::HashSet<PathBuf>,
+    ) {
+        let Ok(content) = std::fs::read_to_string(dir.join(self.manifest_name)) else {
+            return;
+        };
+        let Ok(table) = content.parse::<Table>() else {
+            return;
+        };
+
+        if table.contains_key("workspace") {
+            workspace_roots.insert(dir.to_path_buf());
+        }
+
+        // A root crate can carry both `[package]` and `[workspace]` in the
+        // same file, so registration and workspace-root detection aren't
+        // mutually exclusive.
+        if let Some(pkg) = self.cargo_package_info(dir, &table) {
+            self.packages.push(pkg);
+        }
+    }
+
+    /// Build a [`PackageInfo`] from an already-parsed Cargo.toml `table`,
+    /// resolving `name.workspace = true` / `edition.workspace = true` against
+    /// the nearest ancestor's `[workspace.package]` table.
+    fn cargo_package_info(&self, dir: &Path, table: &Table) -> Option<PackageInfo> {
+        let package = table.get("package")?;
+
+        let name = self.resolve_cargo_field(dir, package, "name")?;
+        let edition = self.resolve_cargo_field(dir, package, "edition");
+
+        Some(PackageInfo {
+            name,
+            root: dir.to_path_buf(),
+            trie: TrieNode::new(),
+            total_files: 0,
+            edition,
+            is_workspace_member: false,
+        })
+    }
+
+    /// Resolve a `[package]` field, following `field.workspace = true` up to
+    /// the nearest ancestor's `[workspace.package]` table.
+    fn resolve_cargo_field(
+        &self,
+        dir: &Path,
+        package: &toml::Value,
+        field: &str,
+    ) -> Option<String> {
+        let value = package.get(field)?;
+        if value.get("workspace").and_then(|w| w.as_bool()) == Some(true) {
+            self.workspace_package_field(dir, field)
+        } else {
+            value.as_str().map(|s| s.to_string())
+        }
+    }
+
+    /// Walk up from `dir` looking for an ancestor `Cargo.toml` whose
+    /// `[workspace.package]` table defines `field`.
+    fn workspace_package_field(&self, dir: &Path, field: &str) -> Option<String> {
+        let mut current = dir.parent();
+        while let Some(candidate) = current {
+            if !candidate.starts_with(&self.project_root) {
+                break;
+            }
+            if let Ok(content) = std::fs::read_to_string(candidate.join("Cargo.toml"))
+                && let Ok(table) = content.parse::<Table>()
+                && let Some(value) = table
+                    .get("workspace")
+                    .and_then(|w| w.get("package"))
+                    .and_then(|p| p.get(field))
+                    .and_then(|v| v.as_str())
+            {
+                return Some(value.to_string());
+            }
+            current = candidate.parent();
+        }
+        None
+    }
+
+    /// Expand `ws_root`'s `[workspace].members`/`exclude` globs so member
+    /// crates are registered as Level-1 packages even if none of their files
+    /// are in `files` yet.
+    fn enroll_workspace_members(
+        &mut self,
+        ws_root: &Path,
+        seen: &mut std::collections::HashSet<PathBuf>,
+    ) {
+        let Ok(content) = std::fs::read_to_string(ws_root.join("Cargo.toml")) else {
+            return;
+        };
+        let Ok(table) = content.parse::<Table>() else {
+            return;
+        };
+        let Some(workspace) = table.get("workspace") else {
+            return;
+        };
+
+        let members = CargoManifestParser
+            .parse(&content)
+            .map(|info| info.members)
+            .unwrap_or_default();
+        let excludes = Self::glob_list(workspace.get("exclude"));
+        let excluded_dirs = Self::expand_workspace_globs(ws_root, &excludes);
+
+        for member_dir in Self::expand_workspace_globs(ws_root, &members) {
+            if excluded_dirs.contains(&member_dir) || seen.contains(&member_dir) {
+                continue;
+            }
+            let Ok(content) = std::fs::read_to_string(member_dir.join("Cargo.toml")) else {
+                continue;
+            };
+            let Ok(table) = content.parse::<Table>() else {
+                continue;
+            };
+            seen.insert(member_dir.clone());
+
+            if let Some(mut pkg) = self.cargo_package_info(&member_dir, &table) {
+                pkg.is_workspace_member = true;
+                self.packages.push(pkg);
+            }
+        }
+    }
+
+    /// Pull a `Vec<String>` out of a TOML array value (e.g. `members`/`exclude`).
+    fn glob_list(value: Option<&toml::Value>) -> Vec<String> {
+        value
+            .and_then(|v| v.as_array())
+            .map(|arr| {
+                arr.iter()
+                    .filter_map(|v| v.as_str().map(String::from))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Expand workspace member/exclude patterns relative to `root`. Only a
+    /// single trailing `*` path segment (e.g. `"crates/*"`) is treated as a
+    /// glob, which covers the common Cargo workspace layouts; anything else
+    /// is a literal relative path.
+    fn expand_workspace_globs(root: &Path, patterns: &[String]) -> Vec<PathBuf> {
+        let mut out = Vec::new();
+        for pattern in patterns {
+            if let Some((prefix, "*")) = pattern.rsplit_once('/') {
+                if let Ok(entries) = std::fs::read_dir(root.join(prefix)) {
+                    out.extend(
+                        entries
+                            .filter_map(|e| e.ok())
+                            .map(|e| e.path())
+                            .filter(|p| p.is_dir()),
+                    );
                 }
+            } else {
+                out.push(root.join(pattern));
             }
-            None
-        } else {
-            // Unknown manifest format - use directory name
-            dir.file_name().and_then(|n| n.to_str()).map(|s| s.to_string())
         }
+        out
     }
 
     // ========================================================================
@@ -315,12 +896,7 @@ impl UnitMetaBuilder {
         }
     }
 
-    fn insert_file(
-        trie: &mut TrieNode,
-        file: &Path,
-        pkg_root: &Path,
-        container_dirs: &[&str],
-    ) {
+    fn insert_file(trie: &mut TrieNode, file: &Path, pkg_root: &Path, container_dirs: &[&str]) {
         let rel_path = match file.strip_prefix(pkg_root) {
             Ok(p) => p,
             Err(_) => return,
@@ -359,6 +935,9 @@ impl UnitMetaBuilder {
         &self,
         components: &[&'a str],
         pkg: &PackageInfo,
+        significance_ratio: f64,
+        dominance_threshold: f64,
+        forbidden_depths: &std::collections::HashSet<usize>,
     ) -> Option<(usize, &'a str)> {
         if components.is_empty() {
             return None;
@@ -389,20 +968,25 @@ impl UnitMetaBuilder {
         // Walk from ROOT to LEAF looking for the best module boundary
         // A good boundary has:
         // 1. Significant siblings (not alone)
-        // 2. Balanced distribution (no sibling dominates >80%)
+        // 2. Balanced distribution (no sibling dominates past `dominance_threshold`)
         //
         // If we find a significant but imbalanced level, keep looking deeper
-        let significance_threshold = (pkg.total_files as f64 * 0.05).max(1.0) as usize;
-        const DOMINANCE_THRESHOLD: f64 = 0.80;
+        let significance_threshold =
+            (pkg.total_files as f64 * significance_ratio).max(1.0) as usize;
 
         for (i, (name, node, sibling_files)) in path_nodes.iter().enumerate() {
+            if forbidden_depths.contains(&i) {
+                // A `.llmcc.toml` forbids treating this directory as a module
+                // boundary - keep looking for a better split deeper.
+                continue;
+            }
             if *sibling_files >= significance_threshold {
                 // Significant siblings - check balance
                 let my_files = node.total_files();
                 let total = my_files + sibling_files;
                 let dominance = my_files as f64 / total as f64;
 
-                if dominance <= DOMINANCE_THRESHOLD {
+                if dominance <= dominance_threshold {
                     // Balanced - use this level
                     return Some((i, *name));
                 }
@@ -410,8 +994,96 @@ impl UnitMetaBuilder {
             }
         }
 
-        // No balanced split found - use the first component
-        path_nodes.first().map(|(name, _, _)| (0, *name))
+        // No balanced split found - use the first non-forbidden component
+        path_nodes
+            .iter()
+            .enumerate()
+            .find(|(i, _)| !forbidden_depths.contains(i))
+            .map(|(i, (name, _, _))| (i, *name))
+    }
+
+    /// Depths (indices into the non-container path components of `rel_path`)
+    /// whose effective `.llmcc.toml` cascade forces `module_boundary = false`,
+    /// i.e. directories [`find_module_for_file`] must never pick as the
+    /// module boundary even if the heuristic otherwise likes them.
+    fn forbidden_module_depths(
+        &self,
+        rel_path: &Path,
+        pkg: &PackageInfo,
+    ) -> std::collections::HashSet<usize> {
+        let mut forbidden = std::collections::HashSet::new();
+        let mut root = pkg.root.clone();
+        let mut non_container_count = 0;
+        for comp in rel_path
+            .parent()
+            .into_iter()
+            .flat_map(|p| p.components())
+            .filter_map(|c| c.as_os_str().to_str())
+        {
+            root = root.join(comp);
+            if !self.is_container(comp) {
+                if Self::effective_override(&pkg.root, &root).module_boundary == Some(false) {
+                    forbidden.insert(non_container_count);
+                }
+                non_container_count += 1;
+            }
+        }
+        forbidden
+    }
+
+    /// Load and merge the `.llmcc.toml` cascade from `pkg_root` down to
+    /// `dir` (inclusive): a directory's own file overlays the values
+    /// inherited from its parents, it doesn't replace the whole config.
+    fn effective_override(pkg_root: &Path, dir: &Path) -> ModuleOverride {
+        let Ok(rel) = dir.strip_prefix(pkg_root) else {
+            return ModuleOverride::default();
+        };
+
+        let mut current = pkg_root.to_path_buf();
+        let mut effective = Self::load_override(&current);
+        for comp in rel.components() {
+            current.push(comp);
+            effective = effective.overlay(&Self::load_override(&current));
+        }
+        effective
+    }
+
+    /// Read and parse `dir/.llmcc.toml`, if present.
+    fn load_override(dir: &Path) -> ModuleOverride {
+        std::fs::read_to_string(dir.join(".llmcc.toml"))
+            .ok()
+            .and_then(|content| content.parse::<Table>().ok())
+            .map(|table| ModuleOverride::from_table(&table))
+            .unwrap_or_default()
+    }
+
+    /// Find the nearest directory, walking up from `file`'s parent to the
+    /// package root, whose effective `.llmcc.toml` cascade forces
+    /// `module_boundary = true`. Returns that directory and its (possibly
+    /// overridden) module name.
+    fn find_module_override(&self, file: &Path, pkg: &PackageInfo) -> Option<(PathBuf, String)> {
+        let mut dir = file.parent();
+        while let Some(current) = dir {
+            if !current.starts_with(&pkg.root) {
+                break;
+            }
+            let effective = Self::effective_override(&pkg.root, current);
+            if effective.module_boundary == Some(true) {
+                let name = effective.module_name.unwrap_or_else(|| {
+                    current
+                        .file_name()
+                        .and_then(|n| n.to_str())
+                        .unwrap_or_default()
+                        .to_string()
+                });
+                return Some((current.to_path_buf(), name));
+            }
+            if current == pkg.root {
+                break;
+            }
+            dir = current.parent();
+        }
+        None
     }
 
     // ========================================================================
@@ -440,6 +1112,8 @@ impl UnitMetaBuilder {
 
         info.package_name = Some(pkg.name.clone());
         info.package_root = Some(pkg.root.clone());
+        info.edition = pkg.edition.clone();
+        info.is_workspace_member = pkg.is_workspace_member;
 
         // Get path components (excluding containers)
         let rel_path = match file.strip_prefix(&pkg.root) {
@@ -455,8 +1129,32 @@ impl UnitMetaBuilder {
             .filter(|c| !self.is_container(c))
             .collect();
 
-        // Find module using per-file bottom-up detection
-        if let Some((depth, module_name)) = self.find_module_for_file(&components, pkg) {
+        // A `.llmcc.toml` that forces `module_boundary = true` on some
+        // ancestor directory wins over the trie heuristic outright, and the
+        // module root points at that directory (not wherever the heuristic
+        // would have split).
+        if let Some((module_root, module_name)) = self.find_module_override(file, pkg) {
+            info.module_name = Some(module_name);
+            info.module_root = Some(module_root);
+            return info;
+        }
+
+        // Otherwise fall back to the per-file bottom-up heuristic, tuned by
+        // any `significance`/`dominance` overrides inherited down to the
+        // file's own directory.
+        let override_dir = file.parent().unwrap_or(pkg.root.as_path());
+        let tuning = Self::effective_override(&pkg.root, override_dir);
+        let significance_ratio = tuning.significance.unwrap_or(0.05);
+        let dominance_threshold = tuning.dominance.unwrap_or(0.80);
+
+        let forbidden_depths = self.forbidden_module_depths(rel_path, pkg);
+        if let Some((depth, module_name)) = self.find_module_for_file(
+            &components,
+            pkg,
+            significance_ratio,
+            dominance_threshold,
+            &forbidden_depths,
+        ) {
             info.module_name = Some(module_name.to_string());
 
             // Reconstruct module root path
@@ -484,3 +1182,252 @@ impl UnitMetaBuilder {
         info
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn glob_match_supports_star_and_double_star() {
+        assert!(glob_match(b"crates/*/src", b"crates/llmcc-core/src"));
+        assert!(!glob_match(b"crates/*/src", b"crates/llmcc-core/sub/src"));
+        assert!(glob_match(b"crates/**/src", b"crates/llmcc-core/sub/src"));
+    }
+
+    #[test]
+    fn glob_match_supports_question_mark_and_literals() {
+        assert!(glob_match(b"lib?.rs", b"lib1.rs"));
+        assert!(!glob_match(b"lib?.rs", b"lib/.rs"));
+        assert!(!glob_match(b"lib.rs", b"lib.rss"));
+    }
+
+    fn empty_builder(project_root: &Path) -> UnitMetaBuilder {
+        UnitMetaBuilder::with_lang_config(&[], project_root, "Cargo.toml", &["src"])
+    }
+
+    fn package_table(content: &str) -> toml::Value {
+        let table: Table = content.parse().expect("parse package fixture");
+        table.get("package").expect("package table").clone()
+    }
+
+    #[test]
+    fn resolve_cargo_field_returns_plain_string_values() {
+        let temp = tempdir().expect("create temp dir");
+        let builder = empty_builder(temp.path());
+        let package = package_table("[package]\nname = \"llmcc-core\"\nedition = \"2021\"\n");
+
+        assert_eq!(
+            builder.resolve_cargo_field(temp.path(), &package, "name"),
+            Some("llmcc-core".to_string())
+        );
+    }
+
+    #[test]
+    fn resolve_cargo_field_follows_workspace_inheritance() {
+        let temp = tempdir().expect("create temp dir");
+        fs::write(
+            temp.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\"child\"]\n\n[workspace.package]\nedition = \"2021\"\n",
+        )
+        .expect("write workspace Cargo.toml");
+
+        let child_dir = temp.path().join("child");
+        fs::create_dir_all(&child_dir).expect("create child dir");
+
+        let builder = empty_builder(temp.path());
+        let package =
+            package_table("[package]\nname = \"child\"\nedition.workspace = true\n");
+
+        assert_eq!(
+            builder.resolve_cargo_field(&child_dir, &package, "edition"),
+            Some("2021".to_string())
+        );
+    }
+
+    #[test]
+    fn resolve_cargo_field_is_none_when_workspace_inheritance_is_unresolvable() {
+        let temp = tempdir().expect("create temp dir");
+        let builder = empty_builder(temp.path());
+        let package = package_table("[package]\nedition.workspace = true\n");
+
+        assert_eq!(
+            builder.resolve_cargo_field(temp.path(), &package, "edition"),
+            None
+        );
+    }
+
+    #[test]
+    fn enroll_workspace_members_registers_member_packages() {
+        let temp = tempdir().expect("create temp dir");
+        fs::write(
+            temp.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        )
+        .expect("write workspace Cargo.toml");
+
+        let member_dir = temp.path().join("crates").join("widget");
+        fs::create_dir_all(&member_dir).expect("create member dir");
+        fs::write(
+            member_dir.join("Cargo.toml"),
+            "[package]\nname = \"widget\"\nedition = \"2021\"\n",
+        )
+        .expect("write member Cargo.toml");
+
+        let mut builder = empty_builder(temp.path());
+        let mut seen = std::collections::HashSet::new();
+        builder.enroll_workspace_members(temp.path(), &mut seen);
+
+        assert!(builder.packages.iter().any(|pkg| pkg.name == "widget"));
+        assert!(seen.contains(&member_dir));
+    }
+
+    #[test]
+    fn enroll_workspace_members_skips_excluded_globs() {
+        let temp = tempdir().expect("create temp dir");
+        fs::write(
+            temp.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\nexclude = [\"crates/excluded\"]\n",
+        )
+        .expect("write workspace Cargo.toml");
+
+        let excluded_dir = temp.path().join("crates").join("excluded");
+        fs::create_dir_all(&excluded_dir).expect("create excluded dir");
+        fs::write(
+            excluded_dir.join("Cargo.toml"),
+            "[package]\nname = \"excluded\"\n",
+        )
+        .expect("write excluded Cargo.toml");
+
+        let mut builder = empty_builder(temp.path());
+        let mut seen = std::collections::HashSet::new();
+        builder.enroll_workspace_members(temp.path(), &mut seen);
+
+        assert!(!builder.packages.iter().any(|pkg| pkg.name == "excluded"));
+    }
+
+    #[test]
+    fn detect_packages_marks_member_crates_with_files_as_workspace_members() {
+        let temp = tempdir().expect("create temp dir");
+        fs::write(
+            temp.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        )
+        .expect("write workspace Cargo.toml");
+
+        let alpha_dir = temp.path().join("crates").join("alpha");
+        let alpha_src = alpha_dir.join("src");
+        fs::create_dir_all(&alpha_src).expect("create alpha src dir");
+        fs::write(
+            alpha_dir.join("Cargo.toml"),
+            "[package]\nname = \"alpha\"\nedition = \"2021\"\n",
+        )
+        .expect("write alpha Cargo.toml");
+        let alpha_lib = alpha_src.join("lib.rs");
+        fs::write(&alpha_lib, b"").expect("create alpha lib.rs");
+
+        let beta_dir = temp.path().join("crates").join("beta");
+        let beta_src = beta_dir.join("src");
+        fs::create_dir_all(&beta_src).expect("create beta src dir");
+        fs::write(
+            beta_dir.join("Cargo.toml"),
+            "[package]\nname = \"beta\"\nedition = \"2021\"\n",
+        )
+        .expect("write beta Cargo.toml");
+        let beta_lib = beta_src.join("lib.rs");
+        fs::write(&beta_lib, b"").expect("create beta lib.rs");
+
+        // Every member has a file of its own in `files`, so each one's
+        // `Cargo.toml` - not the workspace root's - is the nearest manifest
+        // found while climbing from that file.
+        let files = vec![alpha_lib, beta_lib];
+        let builder =
+            UnitMetaBuilder::with_lang_config(&files, temp.path(), "Cargo.toml", &["src"]);
+
+        let alpha = builder
+            .packages
+            .iter()
+            .find(|pkg| pkg.name == "alpha")
+            .expect("alpha should be registered");
+        assert!(
+            alpha.is_workspace_member,
+            "alpha has a file of its own, but is still a workspace member"
+        );
+
+        let beta = builder
+            .packages
+            .iter()
+            .find(|pkg| pkg.name == "beta")
+            .expect("beta should be registered");
+        assert!(
+            beta.is_workspace_member,
+            "beta has a file of its own, but is still a workspace member"
+        );
+    }
+
+    #[test]
+    fn llmcc_toml_forces_module_boundary_true() {
+        let temp = tempdir().expect("create temp dir");
+        fs::write(temp.path().join("Cargo.toml"), "[package]\nname = \"pkg\"\n")
+            .expect("write Cargo.toml");
+
+        let special_dir = temp.path().join("src").join("special");
+        fs::create_dir_all(&special_dir).expect("create special dir");
+        fs::write(
+            special_dir.join(".llmcc.toml"),
+            "module_boundary = true\nmodule_name = \"forced\"\n",
+        )
+        .expect("write .llmcc.toml");
+
+        let file = special_dir.join("deep.rs");
+        fs::write(&file, b"").expect("create deep.rs");
+
+        let builder =
+            UnitMetaBuilder::with_lang_config(&[file.clone()], temp.path(), "Cargo.toml", &["src"]);
+
+        let info = builder.get_module_info(&file);
+        assert_eq!(info.module_name.as_deref(), Some("forced"));
+        assert_eq!(info.module_root.as_deref(), Some(special_dir.as_path()));
+    }
+
+    #[test]
+    fn llmcc_toml_forbids_module_boundary_false() {
+        let temp = tempdir().expect("create temp dir");
+        fs::write(temp.path().join("Cargo.toml"), "[package]\nname = \"pkg\"\n")
+            .expect("write Cargo.toml");
+
+        // `moda` has a balanced, significant sibling (`modb`) so the trie
+        // heuristic would normally pick it as the module boundary at depth
+        // 0 - but its `.llmcc.toml` forbids that, so the boundary must fall
+        // through to `moda/sub` instead.
+        let moda_dir = temp.path().join("src").join("moda");
+        fs::create_dir_all(&moda_dir).expect("create moda dir");
+        fs::write(moda_dir.join(".llmcc.toml"), "module_boundary = false\n")
+            .expect("write .llmcc.toml");
+
+        let sub_dir = moda_dir.join("sub");
+        fs::create_dir_all(&sub_dir).expect("create sub dir");
+        let file1 = sub_dir.join("file1.rs");
+        let file2 = sub_dir.join("file2.rs");
+        fs::write(&file1, b"").expect("create file1.rs");
+        fs::write(&file2, b"").expect("create file2.rs");
+
+        let modb_dir = temp.path().join("src").join("modb");
+        fs::create_dir_all(&modb_dir).expect("create modb dir");
+        let file3 = modb_dir.join("file3.rs");
+        fs::write(&file3, b"").expect("create file3.rs");
+
+        let files = vec![file1.clone(), file2.clone(), file3];
+        let builder =
+            UnitMetaBuilder::with_lang_config(&files, temp.path(), "Cargo.toml", &["src"]);
+
+        let info = builder.get_module_info(&file1);
+        assert_eq!(
+            info.module_name.as_deref(),
+            Some("sub"),
+            "expected the forbidden 'moda' level to be skipped in favor of 'sub'"
+        );
+        assert_eq!(info.module_root.as_deref(), Some(sub_dir.as_path()));
+    }
+}
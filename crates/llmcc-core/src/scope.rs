@@ -546,6 +546,98 @@ impl<'tcx> ScopeStack<'tcx> {
             Some(results)
         }
     }
+
+    /// Fuzzy-match `query` against every symbol name in the global scope,
+    /// returning up to `k` highest-scoring matches (see [`crate::fuzzy`]).
+    /// `kind`, if non-empty, and `unit`, if given, filter exactly like
+    /// [`LookupOptions::kind_filters`]/[`LookupOptions::unit_filters`].
+    pub fn fuzzy_find(
+        &self,
+        query: &str,
+        kind: SymKindSet,
+        unit: Option<usize>,
+        k: usize,
+    ) -> Vec<FuzzyMatch<'tcx>> {
+        if query.is_empty() || k == 0 {
+            return Vec::new();
+        }
+
+        let query_bag = crate::fuzzy::char_bag(query);
+        let mut heap: std::collections::BinaryHeap<std::cmp::Reverse<ScoredSymbol<'tcx>>> =
+            std::collections::BinaryHeap::with_capacity(k + 1);
+
+        self.globals().for_each_symbol(|symbol| {
+            if !kind.is_empty() && !kind.contains(symbol.kind()) {
+                return;
+            }
+            if let Some(unit_index) = unit
+                && symbol.unit_index() != Some(unit_index)
+            {
+                return;
+            }
+
+            let Some(name) = self.interner.resolve_owned(symbol.name) else {
+                return;
+            };
+            if !crate::fuzzy::bag_contains(crate::fuzzy::char_bag(&name), query_bag) {
+                return;
+            }
+            let Some(score) = crate::fuzzy::score_match(query, &name) else {
+                return;
+            };
+
+            heap.push(std::cmp::Reverse(ScoredSymbol { score, symbol }));
+            if heap.len() > k {
+                heap.pop();
+            }
+        });
+
+        let mut matches: Vec<FuzzyMatch<'tcx>> = heap
+            .into_iter()
+            .map(|std::cmp::Reverse(m)| FuzzyMatch {
+                symbol: m.symbol,
+                score: m.score,
+            })
+            .collect();
+        matches.sort_by(|a, b| b.score.cmp(&a.score));
+        matches
+    }
+}
+
+/// A symbol scored by [`ScopeStack::fuzzy_find`], ordered by `score` so it
+/// can sit in the min-heap that keeps only the top-K matches.
+#[derive(Debug, Clone, Copy)]
+struct ScoredSymbol<'tcx> {
+    score: i32,
+    symbol: &'tcx Symbol,
+}
+
+impl PartialEq for ScoredSymbol<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.score == other.score
+    }
+}
+
+impl Eq for ScoredSymbol<'_> {}
+
+impl PartialOrd for ScoredSymbol<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ScoredSymbol<'_> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.score.cmp(&other.score)
+    }
+}
+
+/// One [`ScopeStack::fuzzy_find`] result: a candidate symbol and how well
+/// it matched the query (higher is better).
+#[derive(Debug, Clone, Copy)]
+pub struct FuzzyMatch<'tcx> {
+    pub symbol: &'tcx Symbol,
+    pub score: i32,
 }
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -299,6 +299,11 @@ pub struct Symbol {
     /// Examples: enum variant's FieldOf is the enum; struct field's FieldOf is the struct;
     /// tuple field (by index) FieldOf is the tuple/value being accessed.
     pub field_of: RwLock<Option<SymId>>,
+    /// Byte span `(start, end)` of the HIR node that owns this symbol, i.e.
+    /// `owner`'s `start_byte()..end_byte()`. Set once during collection so
+    /// callers (DOT/JSON rendering) can resolve `file:line:col` without
+    /// re-walking the HIR tree.
+    pub span: RwLock<(usize, usize)>,
 }
 
 impl Clone for Symbol {
@@ -318,6 +323,7 @@ impl Clone for Symbol {
             previous: RwLock::new(*self.previous.read()),
             nested_types: RwLock::new(self.nested_types.read().clone()),
             field_of: RwLock::new(*self.field_of.read()),
+            span: RwLock::new(*self.span.read()),
         }
     }
 }
@@ -349,6 +355,7 @@ impl Symbol {
             previous: RwLock::new(None),
             nested_types: RwLock::new(Vec::new()),
             field_of: RwLock::new(None),
+            span: RwLock::new((0, 0)),
         }
     }
 
@@ -493,6 +500,20 @@ impl Symbol {
         *self.block_id.write() = Some(block_id);
     }
 
+    /// Gets the byte span `(start, end)` of this symbol's owning HIR node.
+    /// `(0, 0)` if the span hasn't been set (e.g. synthetic/global-scope symbols).
+    #[inline]
+    pub fn span(&self) -> (usize, usize) {
+        *self.span.read()
+    }
+
+    /// Sets the byte span of this symbol's owning HIR node. Called once
+    /// during collection from that node's `start_byte()`/`end_byte()`.
+    #[inline]
+    pub fn set_span(&self, start_byte: usize, end_byte: usize) {
+        *self.span.write() = (start_byte, end_byte);
+    }
+
     /// Gets the previous definition of this symbol (for shadowing).
     /// Symbols with the same name in nested scopes form a chain via this field.
     #[inline]
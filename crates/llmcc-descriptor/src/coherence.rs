@@ -0,0 +1,134 @@
+//! Orphan-rule and overlap checking over collected [`ImplDescriptor`]s.
+//!
+//! Locality is resolved through the `DescriptorOrigin` that defines a type's
+//! head constructor, not through the `ImplDescriptor`'s own origin: a type
+//! counts as local when no origin is known for it, or when its origin has no
+//! `crate_source` (i.e. it was found in the crate under analysis rather than
+//! resolved from an external artifact - see `DescriptorOrigin::crate_source`).
+
+use std::collections::HashMap;
+
+use crate::implementation::ImplDescriptor;
+use crate::meta::{DescriptorOrigin, SourceLocation};
+use crate::types::TypeExpr;
+
+/// A coherence-checking failure: either an orphan-rule breach or two
+/// descriptors that structurally overlap.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone)]
+pub enum CoherenceViolation {
+    /// A trait impl where neither the trait, `target_ty`, nor any covering
+    /// argument of `target_ty` is local.
+    Orphan {
+        location: Option<SourceLocation>,
+        trait_ty: TypeExpr,
+        target_ty: TypeExpr,
+    },
+    /// Two descriptors whose `(trait_ty, target_ty)` pair are structurally
+    /// identical - the same inherent or trait impl given twice.
+    Overlap {
+        first: Option<SourceLocation>,
+        second: Option<SourceLocation>,
+        trait_ty: Option<TypeExpr>,
+        target_ty: TypeExpr,
+    },
+}
+
+/// Run both coherence checks over `descriptors` and return every violation
+/// found. `type_origins` maps a type's head constructor name (its last path
+/// segment) to the `DescriptorOrigin` that defines it; types absent from the
+/// map are treated as local. Never panics - partial or all-foreign input
+/// just yields more (or fewer) violations.
+pub fn check_coherence(
+    descriptors: &[ImplDescriptor],
+    type_origins: &HashMap<String, DescriptorOrigin>,
+) -> Vec<CoherenceViolation> {
+    let mut violations = check_orphan_rules(descriptors, type_origins);
+    violations.extend(check_overlaps(descriptors));
+    violations
+}
+
+/// Flag every descriptor whose trait impl is an orphan: the trait is
+/// foreign, `target_ty` itself is foreign, and no argument of `target_ty`
+/// (walked left-to-right) is local enough to cover it.
+pub fn check_orphan_rules(
+    descriptors: &[ImplDescriptor],
+    type_origins: &HashMap<String, DescriptorOrigin>,
+) -> Vec<CoherenceViolation> {
+    let mut violations = Vec::new();
+    for descriptor in descriptors {
+        let Some(trait_ty) = descriptor.trait_ty.as_ref() else {
+            continue;
+        };
+        if is_local(trait_ty, type_origins) || is_local(&descriptor.target_ty, type_origins) {
+            continue;
+        }
+        if covering_argument(&descriptor.target_ty, type_origins).is_some() {
+            continue;
+        }
+        violations.push(CoherenceViolation::Orphan {
+            location: descriptor.origin.location.clone(),
+            trait_ty: trait_ty.clone(),
+            target_ty: descriptor.target_ty.clone(),
+        });
+    }
+    violations
+}
+
+/// Flag every pair of descriptors whose `(trait_ty, target_ty)` are
+/// structurally equal, i.e. the same inherent or trait impl appears twice.
+pub fn check_overlaps(descriptors: &[ImplDescriptor]) -> Vec<CoherenceViolation> {
+    let mut violations = Vec::new();
+    for (i, first) in descriptors.iter().enumerate() {
+        for second in &descriptors[i + 1..] {
+            if first.trait_ty != second.trait_ty || first.target_ty != second.target_ty {
+                continue;
+            }
+            violations.push(CoherenceViolation::Overlap {
+                first: first.origin.location.clone(),
+                second: second.origin.location.clone(),
+                trait_ty: first.trait_ty.clone(),
+                target_ty: first.target_ty.clone(),
+            });
+        }
+    }
+    violations
+}
+
+/// The first of `target_ty`'s generic arguments (left-to-right) that is
+/// local, if any - the "covering argument" that rescues an otherwise-orphan
+/// impl of a foreign trait.
+fn covering_argument<'a>(
+    target_ty: &'a TypeExpr,
+    type_origins: &HashMap<String, DescriptorOrigin>,
+) -> Option<&'a TypeExpr> {
+    target_ty
+        .generics()?
+        .iter()
+        .find(|generic| is_local(generic, type_origins))
+}
+
+/// Whether `ty`'s head constructor is local, per `type_origins`. References
+/// and tuples look through to their contents; callables, `impl Trait`
+/// bounds, and opaque/unknown types have no head constructor and count as
+/// foreign.
+fn is_local(ty: &TypeExpr, type_origins: &HashMap<String, DescriptorOrigin>) -> bool {
+    match ty {
+        TypeExpr::Path { .. } => ty
+            .path_segments()
+            .and_then(|segments| segments.last())
+            .is_some_and(|name| is_local_origin(type_origins.get(name))),
+        TypeExpr::Reference { inner, .. } => is_local(inner, type_origins),
+        TypeExpr::Tuple(items) => items.iter().any(|item| is_local(item, type_origins)),
+        TypeExpr::Callable { .. }
+        | TypeExpr::ImplTrait { .. }
+        | TypeExpr::Opaque { .. }
+        | TypeExpr::Unknown(_) => false,
+    }
+}
+
+/// A type is local when no origin is on record for it, or when its origin
+/// carries no `crate_source` (resolved within the crate under analysis).
+fn is_local_origin(origin: Option<&DescriptorOrigin>) -> bool {
+    origin.map_or(true, |origin| origin.crate_source.is_none())
+}
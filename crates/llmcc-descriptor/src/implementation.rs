@@ -1,5 +1,9 @@
-use crate::meta::DescriptorOrigin;
-use crate::types::TypeExpr;
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use crate::meta::{CrateLocation, DescriptorOrigin, PathKind, SourceLocation};
+use crate::types::{PathQualifier, TypeExpr};
 
 /// Descriptor capturing metadata for Rust `impl` blocks.
 ///
@@ -9,6 +13,9 @@ pub struct ImplDescriptor {
     pub origin: DescriptorOrigin,
     pub target_ty: TypeExpr,
     pub trait_ty: Option<TypeExpr>,
+    pub kind: ImplKind,
+    pub deprecation: Option<Deprecation>,
+    pub stability: Option<Stability>,
 }
 
 impl ImplDescriptor {
@@ -17,6 +24,304 @@ impl ImplDescriptor {
             origin,
             target_ty,
             trait_ty: None,
+            kind: ImplKind::Inherent,
+            deprecation: None,
+            stability: None,
+        }
+    }
+
+    /// Build a descriptor for a trait impl synthesized by a `#[derive(...)]`
+    /// macro rather than written by hand.
+    pub fn derived(
+        origin: DescriptorOrigin,
+        target_ty: TypeExpr,
+        trait_ty: TypeExpr,
+        macro_path: impl Into<String>,
+    ) -> Self {
+        Self {
+            origin,
+            target_ty,
+            trait_ty: Some(trait_ty),
+            kind: ImplKind::Derived {
+                macro_path: macro_path.into(),
+            },
+            deprecation: None,
+            stability: None,
+        }
+    }
+
+    /// Stable 128-bit content fingerprint, analogous to rustc's
+    /// `DefPathHash`/`Svh`: hashes `origin`'s crate-stable identity (its
+    /// `language`/`crate_source`, never `id`/`location`), `target_ty`, and
+    /// `trait_ty`, in that fixed order, followed by the `deprecation` and
+    /// `stability` metadata. Deliberately excludes source byte
+    /// offsets, so moving an impl within a file - or across re-parses of the
+    /// same content - leaves the fingerprint unchanged.
+    pub fn fingerprint(&self) -> [u8; 16] {
+        let mut low = DefaultHasher::new();
+        feed_fingerprint(self, &mut low);
+
+        let mut high = DefaultHasher::new();
+        "llmcc-descriptor-fingerprint".hash(&mut high);
+        feed_fingerprint(self, &mut high);
+
+        let mut bytes = [0u8; 16];
+        bytes[..8].copy_from_slice(&low.finish().to_le_bytes());
+        bytes[8..].copy_from_slice(&high.finish().to_le_bytes());
+        bytes
+    }
+}
+
+fn feed_fingerprint<H: Hasher>(descriptor: &ImplDescriptor, hasher: &mut H) {
+    feed_origin_identity(&descriptor.origin, hasher);
+    feed_type_expr(&descriptor.target_ty, hasher);
+    match &descriptor.trait_ty {
+        Some(trait_ty) => {
+            1u8.hash(hasher);
+            feed_type_expr(trait_ty, hasher);
         }
+        None => 0u8.hash(hasher),
+    }
+    match &descriptor.deprecation {
+        Some(deprecation) => {
+            1u8.hash(hasher);
+            deprecation.since.hash(hasher);
+            deprecation.note.hash(hasher);
+        }
+        None => 0u8.hash(hasher),
+    }
+    match &descriptor.stability {
+        Some(stability) => {
+            1u8.hash(hasher);
+            stability.level.hash(hasher);
+            stability.feature.hash(hasher);
+        }
+        None => 0u8.hash(hasher),
+    }
+}
+
+/// Feed only the crate-stable parts of `origin` - `id`/`location` are tied
+/// to a specific parse/byte-offset and must not affect the fingerprint.
+fn feed_origin_identity<H: Hasher>(origin: &DescriptorOrigin, hasher: &mut H) {
+    origin.language.hash(hasher);
+    match &origin.crate_source {
+        Some(source) => {
+            1u8.hash(hasher);
+            feed_crate_location(source.dylib.as_ref(), hasher);
+            feed_crate_location(source.rlib.as_ref(), hasher);
+            feed_crate_location(source.rmeta.as_ref(), hasher);
+        }
+        None => 0u8.hash(hasher),
+    }
+}
+
+fn feed_crate_location<H: Hasher>(location: Option<&CrateLocation>, hasher: &mut H) {
+    match location {
+        Some(location) => {
+            1u8.hash(hasher);
+            location.path.hash(hasher);
+            let tag: u8 = match location.kind {
+                PathKind::Dependency => 0,
+                PathKind::ExternFlag => 1,
+                PathKind::SearchPath => 2,
+            };
+            tag.hash(hasher);
+        }
+        None => 0u8.hash(hasher),
+    }
+}
+
+fn feed_type_expr<H: Hasher>(ty: &TypeExpr, hasher: &mut H) {
+    match ty {
+        TypeExpr::Path { qualifier, generics } => {
+            0u8.hash(hasher);
+            feed_path_qualifier(qualifier, hasher);
+            generics.len().hash(hasher);
+            for generic in generics {
+                feed_type_expr(generic, hasher);
+            }
+        }
+        TypeExpr::Reference {
+            is_mut,
+            lifetime,
+            inner,
+        } => {
+            1u8.hash(hasher);
+            is_mut.hash(hasher);
+            lifetime.hash(hasher);
+            feed_type_expr(inner, hasher);
+        }
+        TypeExpr::Tuple(items) => {
+            2u8.hash(hasher);
+            items.len().hash(hasher);
+            for item in items {
+                feed_type_expr(item, hasher);
+            }
+        }
+        TypeExpr::Callable { parameters, result } => {
+            3u8.hash(hasher);
+            parameters.len().hash(hasher);
+            for parameter in parameters {
+                feed_type_expr(parameter, hasher);
+            }
+            match result {
+                Some(result) => {
+                    1u8.hash(hasher);
+                    feed_type_expr(result, hasher);
+                }
+                None => 0u8.hash(hasher),
+            }
+        }
+        TypeExpr::ImplTrait { bounds } => {
+            4u8.hash(hasher);
+            bounds.hash(hasher);
+        }
+        TypeExpr::Opaque { language, repr } => {
+            5u8.hash(hasher);
+            language.hash(hasher);
+            repr.hash(hasher);
+        }
+        TypeExpr::Unknown(text) => {
+            6u8.hash(hasher);
+            text.hash(hasher);
+        }
+    }
+}
+
+fn feed_path_qualifier<H: Hasher>(qualifier: &PathQualifier, hasher: &mut H) {
+    match qualifier {
+        PathQualifier::Relative { .. } => 0u8.hash(hasher),
+        PathQualifier::Crate { .. } => 1u8.hash(hasher),
+        PathQualifier::Absolute { .. } => 2u8.hash(hasher),
+        PathQualifier::SelfType { .. } => 3u8.hash(hasher),
+        PathQualifier::Super { levels, .. } => {
+            4u8.hash(hasher);
+            levels.hash(hasher);
+        }
+        PathQualifier::Raw { raw, .. } => {
+            5u8.hash(hasher);
+            raw.hash(hasher);
+        }
+    }
+    qualifier.segments().hash(hasher);
+}
+
+/// `ImplDescriptor`s keyed by [`ImplDescriptor::fingerprint`], so a caller
+/// can diff two analysis runs and recompute only what changed instead of
+/// reprocessing everything.
+#[derive(Debug, Clone, Default)]
+pub struct DescriptorIndex {
+    fingerprints: HashMap<[u8; 16], usize>,
+}
+
+impl DescriptorIndex {
+    /// Index `descriptors` by fingerprint. A fingerprint collision overwrites
+    /// the earlier index with the later one.
+    pub fn build(descriptors: &[ImplDescriptor]) -> Self {
+        let fingerprints = descriptors
+            .iter()
+            .enumerate()
+            .map(|(index, descriptor)| (descriptor.fingerprint(), index))
+            .collect();
+        Self { fingerprints }
+    }
+
+    /// Indices into `current` whose fingerprint is absent from `self` (an
+    /// index built from a prior run) - the descriptors an incremental rerun
+    /// needs to recompute.
+    pub fn changed_since(&self, current: &[ImplDescriptor]) -> Vec<usize> {
+        let mut changed: Vec<usize> = current
+            .iter()
+            .enumerate()
+            .filter(|(_, descriptor)| !self.fingerprints.contains_key(&descriptor.fingerprint()))
+            .map(|(index, _)| index)
+            .collect();
+        changed.sort_unstable();
+        changed
+    }
+}
+
+/// How an `impl` block came to exist, so generated summaries can collapse or
+/// hide derive-synthesized impls instead of treating them as hand-written.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImplKind {
+    /// An inherent impl (`trait_ty` is `None`).
+    Inherent,
+    /// A trait impl written out by hand in source.
+    HandWrittenTrait,
+    /// A trait impl synthesized by a derive macro, e.g. `"derive(Clone)"`.
+    Derived { macro_path: String },
+}
+
+/// Target types of the descriptors in `descriptors` whose `kind` is `kind`.
+pub fn target_tys_of_kind<'a>(
+    descriptors: &'a [ImplDescriptor],
+    kind: &'a ImplKind,
+) -> impl Iterator<Item = &'a TypeExpr> {
+    descriptors
+        .iter()
+        .filter(move |descriptor| &descriptor.kind == kind)
+        .map(|descriptor| &descriptor.target_ty)
+}
+
+/// Trait types of the descriptors in `descriptors` whose `kind` is `kind`,
+/// skipping any inherent impls that carry no `trait_ty`.
+pub fn trait_tys_of_kind<'a>(
+    descriptors: &'a [ImplDescriptor],
+    kind: &'a ImplKind,
+) -> impl Iterator<Item = &'a TypeExpr> {
+    descriptors
+        .iter()
+        .filter(move |descriptor| &descriptor.kind == kind)
+        .filter_map(|descriptor| descriptor.trait_ty.as_ref())
+}
+
+/// A `#[deprecated]` attribute's `since`/`note` fields, either of which Rust
+/// allows to be omitted.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deprecation {
+    pub since: Option<String>,
+    pub note: Option<String>,
+}
+
+/// Library-stability annotation, mirroring rustc's `#[stable]`/`#[unstable]`
+/// attributes.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stability {
+    pub level: StabilityLevel,
+    pub feature: Option<String>,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StabilityLevel {
+    Stable,
+    Unstable,
+}
+
+/// Per-item metadata queries over a descriptor, mirroring the
+/// `stability`/`deprecation`/`def_span` family of queries rustc's
+/// `CrateStore` exposes - lets a consumer surface "this impl is deprecated
+/// since X" or locate the defining span without re-parsing the source.
+pub trait DescriptorQuery {
+    fn stability(&self) -> Option<&Stability>;
+    fn deprecation(&self) -> Option<&Deprecation>;
+    fn span(&self) -> Option<&SourceLocation>;
+}
+
+impl DescriptorQuery for ImplDescriptor {
+    fn stability(&self) -> Option<&Stability> {
+        self.stability.as_ref()
+    }
+
+    fn deprecation(&self) -> Option<&Deprecation> {
+        self.deprecation.as_ref()
+    }
+
+    fn span(&self) -> Option<&SourceLocation> {
+        self.origin.location.as_ref()
     }
 }
@@ -6,11 +6,15 @@ use serde::{Deserialize, Serialize};
 pub mod builder;
 pub mod call;
 pub mod class;
+pub mod coherence;
 pub mod enumeration;
 pub mod function;
+pub mod implementation;
 pub mod import;
 pub mod meta;
 pub mod module;
+#[cfg(feature = "serde")]
+pub mod store;
 pub mod structure;
 pub mod types;
 pub mod variable;
@@ -19,11 +23,15 @@ pub mod visibility;
 pub use builder::*;
 pub use call::*;
 pub use class::*;
+pub use coherence::*;
 pub use enumeration::*;
 pub use function::*;
+pub use implementation::*;
 pub use import::*;
 pub use meta::*;
 pub use module::*;
+#[cfg(feature = "serde")]
+pub use store::*;
 pub use structure::*;
 pub use types::*;
 pub use variable::*;
@@ -63,6 +63,60 @@ impl SourceLocation {
     }
 }
 
+/// Where a located crate artifact came from - mirrors rustc's `PathKind`,
+/// letting tooling distinguish a dependency resolved from the registry from
+/// one given explicitly or found on a bare search path.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathKind {
+    /// Resolved as a registered dependency.
+    Dependency,
+    /// Given explicitly (e.g. an `--extern name=path` flag).
+    ExternFlag,
+    /// Found on a bare search path, not a registered dependency.
+    SearchPath,
+}
+
+/// One located crate artifact: a path plus where it was found.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrateLocation {
+    pub path: String,
+    pub kind: PathKind,
+}
+
+impl CrateLocation {
+    pub fn new(path: impl Into<String>, kind: PathKind) -> Self {
+        Self {
+            path: path.into(),
+            kind,
+        }
+    }
+}
+
+/// On-disk provenance for a crate, modeled on rustc's `CrateSource`, so
+/// tooling merging descriptors from multiple crates can tell which artifact
+/// an `impl` or `trait_ty` originated from when the same type name appears
+/// in several crates. Each of `dylib`/`rlib`/`rmeta` is an optional public
+/// field; nothing checks that at least one is populated, and
+/// `CrateSource::default()` has none.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CrateSource {
+    pub dylib: Option<CrateLocation>,
+    pub rlib: Option<CrateLocation>,
+    pub rmeta: Option<CrateLocation>,
+}
+
+impl CrateSource {
+    /// Iterate over whichever of `dylib`/`rlib`/`rmeta` are populated.
+    pub fn paths(&self) -> impl Iterator<Item = &CrateLocation> {
+        [self.dylib.as_ref(), self.rlib.as_ref(), self.rmeta.as_ref()]
+            .into_iter()
+            .flatten()
+    }
+}
+
 /// Shared origin data carried by every descriptor instance.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -70,6 +124,7 @@ pub struct DescriptorOrigin {
     pub language: LanguageKey,
     pub id: Option<DescriptorId>,
     pub location: Option<SourceLocation>,
+    pub crate_source: Option<CrateSource>,
 }
 
 impl DescriptorOrigin {
@@ -78,6 +133,7 @@ impl DescriptorOrigin {
             language,
             id: None,
             location: None,
+            crate_source: None,
         }
     }
 
@@ -90,6 +146,11 @@ impl DescriptorOrigin {
         self.location = Some(location);
         self
     }
+
+    pub fn with_crate_source(mut self, crate_source: CrateSource) -> Self {
+        self.crate_source = Some(crate_source);
+        self
+    }
 }
 
 /// Extensible metadata bag for language-specific add-ons.
@@ -0,0 +1,132 @@
+//! On-disk descriptor metadata, analogous to rustc's `.rmeta`: a versioned,
+//! `serde`-backed blob a downstream tool can load instead of re-parsing a
+//! dependency's source. Only built when the `serde` feature is enabled,
+//! since that's what makes [`ImplDescriptor`] (and the `TypeExpr`/
+//! `DescriptorOrigin` it references) serializable in the first place.
+
+use std::fs;
+use std::path::Path;
+
+use llmcc_error::{Error, ErrorKind, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::implementation::ImplDescriptor;
+
+/// Current on-disk format version. Bump whenever the shape of
+/// [`ImplDescriptor`] (or anything it references) changes incompatibly, so
+/// [`DescriptorStore::load`] rejects blobs written by an older/newer build
+/// instead of silently misreading them.
+pub const DESCRIPTOR_STORE_FORMAT_VERSION: u32 = 1;
+
+/// Header written ahead of the descriptor payload, checked on load before
+/// any descriptor is deserialized.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DescriptorStoreHeader {
+    format_version: u32,
+    crate_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DescriptorStoreFile {
+    header: DescriptorStoreHeader,
+    impls: Vec<ImplDescriptor>,
+}
+
+/// A crate's collected `ImplDescriptor`s, loadable/saveable as a single
+/// versioned blob.
+#[derive(Debug, Clone)]
+pub struct DescriptorStore {
+    crate_name: String,
+    impls: Vec<ImplDescriptor>,
+}
+
+impl DescriptorStore {
+    /// Wrap `impls` as the descriptor set for `crate_name`, ready to
+    /// [`save`](Self::save).
+    pub fn new(crate_name: impl Into<String>, impls: Vec<ImplDescriptor>) -> Self {
+        Self {
+            crate_name: crate_name.into(),
+            impls,
+        }
+    }
+
+    pub fn crate_name(&self) -> &str {
+        &self.crate_name
+    }
+
+    /// Iterate over the decoded descriptors, in their original order.
+    pub fn impls(&self) -> impl Iterator<Item = &ImplDescriptor> {
+        self.impls.iter()
+    }
+
+    /// Serialize this store's descriptors, with a version/crate-identity
+    /// header, to `path`.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let file = DescriptorStoreFile {
+            header: DescriptorStoreHeader {
+                format_version: DESCRIPTOR_STORE_FORMAT_VERSION,
+                crate_name: self.crate_name.clone(),
+            },
+            impls: self.impls.clone(),
+        };
+
+        let json = serde_json::to_string(&file).map_err(|err| {
+            Error::new(
+                ErrorKind::SerializationFailed,
+                format!("failed to serialize descriptor store: {err}"),
+            )
+        })?;
+
+        fs::write(path, json)
+            .map_err(|err| Error::from(err).with_context("path", path.display().to_string()))
+    }
+
+    /// Load a descriptor store from `path`, rejecting it if its format
+    /// version or source crate identity doesn't match `expected_crate_name`
+    /// - a stale or mismatched blob is a hard error, not a silent no-op.
+    pub fn load(path: impl AsRef<Path>, expected_crate_name: &str) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path).map_err(|err| {
+            Error::new(
+                ErrorKind::FileNotFound,
+                format!("failed to read descriptor store {}: {err}", path.display()),
+            )
+        })?;
+
+        let file: DescriptorStoreFile = serde_json::from_str(&contents).map_err(|err| {
+            Error::new(
+                ErrorKind::DeserializationFailed,
+                format!("invalid descriptor store at {}: {err}", path.display()),
+            )
+        })?;
+
+        if file.header.format_version != DESCRIPTOR_STORE_FORMAT_VERSION {
+            return Err(Error::new(
+                ErrorKind::InvalidFormat,
+                format!(
+                    "descriptor store {} has format version {}, expected {}",
+                    path.display(),
+                    file.header.format_version,
+                    DESCRIPTOR_STORE_FORMAT_VERSION
+                ),
+            ));
+        }
+        if file.header.crate_name != expected_crate_name {
+            return Err(Error::new(
+                ErrorKind::InvalidFormat,
+                format!(
+                    "descriptor store {} was built for crate `{}`, expected `{}`",
+                    path.display(),
+                    file.header.crate_name,
+                    expected_crate_name
+                ),
+            ));
+        }
+
+        Ok(Self {
+            crate_name: file.header.crate_name,
+            impls: file.impls,
+        })
+    }
+}
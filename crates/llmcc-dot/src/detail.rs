@@ -132,6 +132,10 @@ fn render_node(output: &mut String, node: &RenderNode, indent_level: usize) {
         let _ = write!(output, ", full_path=\"{}\"", escape_label(location));
     }
 
+    if let Some((start, end)) = node.span {
+        let _ = write!(output, ", span=\"{}-{}\"", start, end);
+    }
+
     if let Some(sym_kind) = &node.sym_kind {
         let _ = write!(output, ", sym_ty=\"{:?}\"", sym_kind);
         let shape = shape_for_kind(Some(*sym_kind));
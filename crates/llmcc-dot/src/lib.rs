@@ -93,6 +93,25 @@ fn render_file_level(
     project: &ProjectGraph,
     options: &RenderOptions,
 ) -> String {
+    let (filtered_nodes, filtered_edges) = filtered_nodes_and_edges(project, nodes, edges, options);
+
+    if filtered_nodes.is_empty() {
+        return "digraph G {\n}\n".to_string();
+    }
+
+    let tree = detail::build_component_tree(&filtered_nodes, ComponentDepth::File);
+    detail::render_dot(&filtered_nodes, &filtered_edges, &tree)
+}
+
+/// Apply the same PageRank top-K selection and orphan-node filtering every
+/// file-level renderer uses, so alternate output formats (DOT, JSON, ...)
+/// describe the same subgraph.
+pub fn filtered_nodes_and_edges(
+    project: &ProjectGraph,
+    nodes: &[RenderNode],
+    edges: BTreeSet<RenderEdge>,
+    options: &RenderOptions,
+) -> (Vec<RenderNode>, BTreeSet<RenderEdge>) {
     let mut filtered_nodes = nodes.to_vec();
 
     // Apply PageRank filtering if requested
@@ -129,10 +148,5 @@ fn render_file_level(
         filtered_nodes.retain(|n| connected_nodes.contains(&n.block_id));
     }
 
-    if filtered_nodes.is_empty() {
-        return "digraph G {\n}\n".to_string();
-    }
-
-    let tree = detail::build_component_tree(&filtered_nodes, ComponentDepth::File);
-    detail::render_dot(&filtered_nodes, &filtered_edges, &tree)
+    (filtered_nodes, filtered_edges)
 }
@@ -0,0 +1,17 @@
+//! LSP server exposing call-hierarchy and find-references over a `ProjectGraph`.
+//!
+//! `ProjectGraph`/`UnitGraph` already track precise `DependsOn`/`DependedBy`
+//! edges between function and type blocks (`BlockRelationMap::get_depends`/
+//! `get_depended`), which is exactly the data `callHierarchy/incomingCalls`,
+//! `callHierarchy/outgoingCalls`, and `textDocument/references` need. This
+//! crate runs the same `CompileCtxt` -> `collect_symbols` -> `bind_symbols`
+//! -> `build_llmcc_graph` pipeline `llmcc-cli` uses, snapshots the resulting
+//! graph into an address-free [`AnalysisSnapshot`], and answers editor
+//! requests against that snapshot over stdio JSON-RPC.
+
+pub mod protocol;
+pub mod server;
+pub mod snapshot;
+
+pub use server::{LspServer, ServerOptions};
+pub use snapshot::{AnalysisSnapshot, BlockInfo};
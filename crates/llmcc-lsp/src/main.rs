@@ -0,0 +1,95 @@
+use std::io::{BufReader, stdin, stdout};
+
+use anyhow::{Result, anyhow};
+use clap::Parser;
+use serde_json::Value;
+
+use llmcc_core::lang_def::{LanguageTrait, LanguageTraitImpl};
+use llmcc_lsp::protocol::{error_response, read_message, response, write_message};
+use llmcc_lsp::{LspServer, ServerOptions};
+use llmcc_python::LangPython;
+use llmcc_rust::LangRust;
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "llmcc-lsp",
+    about = "LSP server serving call-hierarchy/references from llmcc's ProjectGraph",
+    version
+)]
+struct Args {
+    /// Language to use: 'rust' or 'python'.
+    #[arg(long, value_name = "LANG", default_value = "rust")]
+    lang: String,
+
+    #[command(flatten)]
+    server: ServerOptions,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+
+    match args.lang.as_str() {
+        "rust" => run::<LangRust>(args.server),
+        "python" => run::<LangPython>(args.server),
+        other => Err(anyhow!("Unknown language: {other}")),
+    }
+}
+
+fn collect_files<L: LanguageTrait>(opts: &ServerOptions) -> Vec<String> {
+    if !opts.files.is_empty() {
+        return opts.files.clone();
+    }
+    let supported_exts = L::supported_extensions();
+    let mut files = Vec::new();
+    for dir in &opts.dirs {
+        for entry in walkdir::WalkDir::new(dir)
+            .into_iter()
+            .filter_map(|e| e.ok())
+        {
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let Some(ext) = entry.path().extension().and_then(|ext| ext.to_str()) else {
+                continue;
+            };
+            if supported_exts.contains(&ext) {
+                files.push(entry.path().to_string_lossy().into_owned());
+            }
+        }
+    }
+    files
+}
+
+/// Serve JSON-RPC requests over stdio until the client disconnects.
+fn run<L: LanguageTraitImpl>(opts: ServerOptions) -> Result<()> {
+    let files = collect_files::<L>(&opts);
+    let mut server =
+        LspServer::<L>::new(files).map_err(|err| anyhow!("failed to build project graph: {err}"))?;
+
+    let mut reader = BufReader::new(stdin());
+    let mut writer = stdout();
+
+    while let Some(message) = read_message(&mut reader)? {
+        let Some(method) = message.get("method").and_then(Value::as_str) else {
+            continue;
+        };
+        let params = message.get("params").cloned().unwrap_or(Value::Null);
+
+        let Some(result) = server.handle(method, &params) else {
+            continue;
+        };
+
+        let Some(id) = message.get("id").cloned() else {
+            // Notifications (e.g. didChange) carry no id and get no reply.
+            continue;
+        };
+
+        let reply = match result {
+            Value::Null => error_response(id, -32603, "no result for request"),
+            result => response(id, result),
+        };
+        write_message(&mut writer, &reply)?;
+    }
+
+    Ok(())
+}
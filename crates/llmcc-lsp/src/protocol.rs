@@ -0,0 +1,51 @@
+//! Minimal JSON-RPC-over-stdio framing, the transport LSP clients expect:
+//! a `Content-Length` header, a blank line, then a UTF-8 JSON body.
+
+use std::io::{self, BufRead, Write};
+
+use serde_json::{Value, json};
+
+/// Read one framed JSON-RPC message from `reader`, or `Ok(None)` on EOF.
+pub fn read_message(reader: &mut impl BufRead) -> io::Result<Option<Value>> {
+    let mut content_length = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some(value) = line.strip_prefix("Content-Length:") {
+            content_length = value.trim().parse::<usize>().ok();
+        }
+    }
+
+    let content_length =
+        content_length.ok_or_else(|| io::Error::other("missing Content-Length header"))?;
+    let mut body = vec![0u8; content_length];
+    reader.read_exact(&mut body)?;
+
+    serde_json::from_slice(&body)
+        .map(Some)
+        .map_err(|err| io::Error::other(format!("invalid JSON-RPC body: {err}")))
+}
+
+/// Write `message` to `writer`, framed with a `Content-Length` header.
+pub fn write_message(writer: &mut impl Write, message: &Value) -> io::Result<()> {
+    let body = serde_json::to_vec(message)?;
+    write!(writer, "Content-Length: {}\r\n\r\n", body.len())?;
+    writer.write_all(&body)?;
+    writer.flush()
+}
+
+/// Build a JSON-RPC 2.0 success response for request `id`.
+pub fn response(id: Value, result: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "result": result })
+}
+
+/// Build a JSON-RPC 2.0 error response for request `id`.
+pub fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
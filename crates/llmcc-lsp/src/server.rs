@@ -0,0 +1,195 @@
+//! Request handling: resolves editor positions against an
+//! [`AnalysisSnapshot`] and answers call-hierarchy/references requests.
+
+use std::marker::PhantomData;
+
+use clap::Args;
+use serde_json::{Value, json};
+
+use llmcc_cli::options::{CommonTestOptions, GraphOptions};
+use llmcc_core::block::BlockKind;
+use llmcc_core::lang_def::LanguageTraitImpl;
+use llmcc_core::{BlockId, DynError};
+
+use crate::snapshot::{AnalysisSnapshot, BlockInfo};
+
+/// Server configuration: which files to analyze, plus the same
+/// `GraphOptions`/`CommonTestOptions` the rest of the CLI tooling uses, so a
+/// project already configured for `llmcc --component-depth`/`--pagerank-top-k`
+/// doesn't need a second set of flags for the LSP binary.
+#[derive(Args, Debug, Clone)]
+pub struct ServerOptions {
+    /// Individual files to analyze (repeatable).
+    #[arg(short = 'f', long = "file", value_name = "FILE", num_args = 1..)]
+    pub files: Vec<String>,
+
+    /// Directories to scan recursively (repeatable).
+    #[arg(short = 'd', long = "dir", value_name = "DIR", num_args = 1..)]
+    pub dirs: Vec<String>,
+
+    #[command(flatten)]
+    pub common: CommonTestOptions,
+}
+
+impl ServerOptions {
+    pub fn graph(&self) -> &GraphOptions {
+        &self.common.graph
+    }
+}
+
+/// Stateful LSP server: builds its [`AnalysisSnapshot`] from `files` in
+/// [`new`](Self::new) and rebuilds it on every `didOpen`/`didChange`
+/// notification (there's no per-unit incremental re-bind - see
+/// `llmcc_core::incremental` - so a rebuild redoes the full pipeline over
+/// the same file list). `initialize` only reports capabilities.
+pub struct LspServer<L: LanguageTraitImpl> {
+    files: Vec<String>,
+    snapshot: AnalysisSnapshot,
+    _lang: PhantomData<L>,
+}
+
+impl<L: LanguageTraitImpl> LspServer<L> {
+    /// Build a server over `files`, running the pipeline once up front.
+    pub fn new(files: Vec<String>) -> Result<Self, DynError> {
+        let snapshot = AnalysisSnapshot::build::<L>(&files)?;
+        Ok(Self {
+            files,
+            snapshot,
+            _lang: PhantomData,
+        })
+    }
+
+    /// Re-run the pipeline over the same file list, e.g. after `didChange`.
+    pub fn rebuild(&mut self) -> Result<(), DynError> {
+        self.snapshot = AnalysisSnapshot::build::<L>(&self.files)?;
+        Ok(())
+    }
+
+    /// Dispatch one JSON-RPC request/notification to its handler. Returns
+    /// `None` for notifications (no response is sent) or unrecognized
+    /// methods.
+    pub fn handle(&mut self, method: &str, params: &Value) -> Option<Value> {
+        match method {
+            "initialize" => Some(json!({
+                "capabilities": {
+                    "callHierarchyProvider": true,
+                    "referencesProvider": true,
+                }
+            })),
+            "textDocument/didOpen" | "textDocument/didChange" => {
+                if let Err(err) = self.rebuild() {
+                    tracing::warn!(?err, "llmcc-lsp: rebuild after didChange failed");
+                }
+                None
+            }
+            "textDocument/prepareCallHierarchy" => {
+                self.resolve_item(params).map(|item| json!([item]))
+            }
+            "callHierarchy/incomingCalls" => self.incoming_calls(params),
+            "callHierarchy/outgoingCalls" => self.outgoing_calls(params),
+            "textDocument/references" => self.references(params),
+            _ => None,
+        }
+    }
+
+    fn resolve_position(&self, params: &Value) -> Option<BlockId> {
+        let position = params.get("position").unwrap_or(params);
+        let file = params
+            .get("textDocument")?
+            .get("uri")?
+            .as_str()?
+            .trim_start_matches("file://");
+        let line = position.get("line")?.as_u64()? as usize + 1;
+        let col = position.get("character")?.as_u64()? as usize + 1;
+        self.snapshot.resolve_position(file, line, col)
+    }
+
+    fn resolve_item(&self, params: &Value) -> Option<Value> {
+        let block = self.resolve_position(params)?;
+        let info = self.snapshot.blocks.get(&block)?;
+        Some(block_to_item(block, info))
+    }
+
+    fn block_from_item(&self, params: &Value) -> Option<BlockId> {
+        let data = params.get("item")?.get("data")?;
+        Some(BlockId(data.as_u64()? as u32))
+    }
+
+    fn incoming_calls(&self, params: &Value) -> Option<Value> {
+        let block = self.block_from_item(params)?;
+        let calls: Vec<Value> = self
+            .snapshot
+            .depended_by(block)
+            .iter()
+            .filter_map(|caller| {
+                let info = self.snapshot.blocks.get(caller)?;
+                Some(json!({ "from": block_to_item(*caller, info), "fromRanges": [] }))
+            })
+            .collect();
+        Some(Value::Array(calls))
+    }
+
+    fn outgoing_calls(&self, params: &Value) -> Option<Value> {
+        let block = self.block_from_item(params)?;
+        let calls: Vec<Value> = self
+            .snapshot
+            .depends_on(block)
+            .iter()
+            .filter_map(|callee| {
+                let info = self.snapshot.blocks.get(callee)?;
+                Some(json!({ "to": block_to_item(*callee, info), "fromRanges": [] }))
+            })
+            .collect();
+        Some(Value::Array(calls))
+    }
+
+    fn references(&self, params: &Value) -> Option<Value> {
+        let block = self.resolve_position(params)?;
+        let locations: Vec<Value> = self
+            .snapshot
+            .depended_by(block)
+            .iter()
+            .filter_map(|referrer| {
+                let info = self.snapshot.blocks.get(referrer)?;
+                Some(location(info))
+            })
+            .collect();
+        Some(Value::Array(locations))
+    }
+}
+
+fn block_to_item(block: BlockId, info: &BlockInfo) -> Value {
+    json!({
+        "name": info.name,
+        "kind": lsp_symbol_kind(info.kind),
+        "uri": format!("file://{}", info.file_path),
+        "range": range(info),
+        "selectionRange": range(info),
+        "data": block.0,
+    })
+}
+
+fn location(info: &BlockInfo) -> Value {
+    json!({ "uri": format!("file://{}", info.file_path), "range": range(info) })
+}
+
+fn range(info: &BlockInfo) -> Value {
+    json!({
+        "start": { "line": info.start.0 - 1, "character": info.start.1 - 1 },
+        "end": { "line": info.end.0 - 1, "character": info.end.1 - 1 },
+    })
+}
+
+/// Map a `BlockKind` to the closest `SymbolKind` in the LSP spec.
+fn lsp_symbol_kind(kind: BlockKind) -> u32 {
+    match kind {
+        BlockKind::Func | BlockKind::Method | BlockKind::Closure => 12,
+        BlockKind::Class => 5,
+        BlockKind::Trait => 11,
+        BlockKind::Enum => 10,
+        BlockKind::Const => 14,
+        BlockKind::Field | BlockKind::Parameter => 8,
+        BlockKind::Module => 2,
+        _ => 1,
+    }
+}
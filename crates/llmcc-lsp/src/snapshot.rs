@@ -0,0 +1,136 @@
+//! Flattened, address-free view of a `ProjectGraph` build.
+//!
+//! `CompileCtxt`/`ProjectGraph` are arena-backed and self-referential, which
+//! makes them awkward to hold across LSP requests (the server has to
+//! rebuild on every `didChange` anyway, since per-unit incremental
+//! re-binding isn't implemented yet - see `llmcc_core::incremental`). So
+//! each build is flattened into this owned snapshot and the arena is
+//! dropped; requests only ever touch `AnalysisSnapshot`.
+
+use std::collections::HashMap;
+
+use llmcc_core::block::BlockKind;
+use llmcc_core::context::CompileCtxt;
+use llmcc_core::graph::ProjectGraph;
+use llmcc_core::graph_builder::{GraphBuildOption, build_llmcc_graph};
+use llmcc_core::ir_builder::{IrBuildOption, build_llmcc_ir};
+use llmcc_core::lang_def::LanguageTraitImpl;
+use llmcc_core::{BlockId, DynError};
+use llmcc_resolver::{ResolverOption, bind_symbols_with, collect_symbols_with};
+
+/// Everything an LSP request needs about one block: its display name, kind,
+/// owning file, and source span (both byte offsets and resolved 1-based
+/// line/column, so `resolve_position` and call-hierarchy replies share the
+/// same coordinates an editor sends).
+#[derive(Debug, Clone)]
+pub struct BlockInfo {
+    pub name: String,
+    pub kind: BlockKind,
+    pub file_path: String,
+    pub span: (usize, usize),
+    pub start: (usize, usize),
+    pub end: (usize, usize),
+}
+
+/// A flattened snapshot of one `ProjectGraph` build: per-block metadata plus
+/// the `DependsOn`/`DependedBy` adjacency `callHierarchy`/`references`
+/// requests walk.
+#[derive(Debug, Clone, Default)]
+pub struct AnalysisSnapshot {
+    pub blocks: HashMap<BlockId, BlockInfo>,
+    depends: HashMap<BlockId, Vec<BlockId>>,
+    depended: HashMap<BlockId, Vec<BlockId>>,
+}
+
+impl AnalysisSnapshot {
+    /// Run the full parse/collect/bind/graph pipeline over `files` and
+    /// flatten the result. Mirrors `llmcc_cli::run_main`, minus the
+    /// rendering tail - this crate only needs the connected `ProjectGraph`.
+    pub fn build<L: LanguageTraitImpl>(files: &[String]) -> Result<Self, DynError> {
+        let cc = CompileCtxt::from_files::<L>(files)?;
+        build_llmcc_ir::<L>(&cc, IrBuildOption::default())?;
+
+        let resolver_option = ResolverOption::default().with_sequential(false);
+        let globals = collect_symbols_with::<L>(&cc, &resolver_option);
+        bind_symbols_with::<L>(&cc, globals, &resolver_option);
+
+        let mut pg = ProjectGraph::new(&cc);
+        let unit_graphs = build_llmcc_graph::<L>(&cc, GraphBuildOption::new())?;
+        pg.add_children(unit_graphs);
+        pg.connect_blocks();
+
+        Ok(Self::from_graph(&cc, &pg))
+    }
+
+    fn from_graph<'tcx>(cc: &'tcx CompileCtxt<'tcx>, pg: &ProjectGraph<'tcx>) -> Self {
+        let mut blocks = HashMap::new();
+        let mut depends = HashMap::new();
+        let mut depended = HashMap::new();
+
+        for (block_id, unit_index, name, kind) in cc.get_all_blocks() {
+            let file_path = cc.file_path(unit_index).unwrap_or("").to_string();
+            let unit_graph = pg.unit_graph(unit_index);
+            let span = unit_graph
+                .and_then(|unit| unit.span_of(cc, block_id))
+                .unwrap_or((0, 0));
+            let unit = cc.compile_unit(unit_index);
+            blocks.insert(
+                block_id,
+                BlockInfo {
+                    name: name.unwrap_or_default(),
+                    kind,
+                    file_path,
+                    span,
+                    start: unit.line_col(span.0),
+                    end: unit.line_col(span.1),
+                },
+            );
+
+            let out = cc.related_map.get_depends(block_id);
+            if !out.is_empty() {
+                depends.insert(block_id, out);
+            }
+            let in_ = cc.related_map.get_depended(block_id);
+            if !in_.is_empty() {
+                depended.insert(block_id, in_);
+            }
+        }
+
+        Self {
+            blocks,
+            depends,
+            depended,
+        }
+    }
+
+    /// Blocks `block` calls/depends on (`callHierarchy/outgoingCalls`).
+    pub fn depends_on(&self, block: BlockId) -> &[BlockId] {
+        self.depends.get(&block).map_or(&[], Vec::as_slice)
+    }
+
+    /// Blocks that call/depend on `block` (`callHierarchy/incomingCalls`,
+    /// `textDocument/references`).
+    pub fn depended_by(&self, block: BlockId) -> &[BlockId] {
+        self.depended.get(&block).map_or(&[], Vec::as_slice)
+    }
+
+    /// Resolve an editor position (`file`, 1-based `line`/`col`) to the
+    /// innermost block whose span contains it - the smallest span wins so a
+    /// click inside a method body resolves to the method, not its class.
+    pub fn resolve_position(&self, file: &str, line: usize, col: usize) -> Option<BlockId> {
+        self.blocks
+            .iter()
+            .filter(|(_, info)| info.file_path == file)
+            .filter(|(_, info)| position_in_span((line, col), info.start, info.end))
+            .min_by_key(|(_, info)| {
+                let (sl, sc) = info.start;
+                let (el, ec) = info.end;
+                (el.saturating_sub(sl), ec.saturating_sub(sc))
+            })
+            .map(|(id, _)| *id)
+    }
+}
+
+fn position_in_span(pos: (usize, usize), start: (usize, usize), end: (usize, usize)) -> bool {
+    pos >= start && pos <= end
+}
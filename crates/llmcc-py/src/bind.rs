@@ -19,6 +19,11 @@ type ScopeEnterFn<'tcx> =
 pub struct BinderVisitor<'tcx> {
     #[allow(dead_code)]
     config: ResolverOption,
+    /// Stack of enclosing `match` subjects' types, innermost last, so a
+    /// `case_clause` nested anywhere under a `match_statement` can bind its
+    /// patterns against the right subject even through intervening `block`
+    /// nodes.
+    match_subjects: Vec<Option<&'tcx Symbol>>,
     phantom: std::marker::PhantomData<&'tcx ()>,
 }
 
@@ -26,6 +31,7 @@ impl<'tcx> BinderVisitor<'tcx> {
     fn new(config: ResolverOption) -> Self {
         Self {
             config,
+            match_subjects: Vec::new(),
             phantom: std::marker::PhantomData,
         }
     }
@@ -58,6 +64,39 @@ impl<'tcx> BinderVisitor<'tcx> {
         self.visit_children(unit, node, scopes, scopes.top(), child_parent);
         scopes.pop_until(depth);
     }
+
+    /// Push a scope for the `case` clause (pattern bindings live there) and
+    /// bind its pattern(s) against `self.match_subjects`' innermost entry,
+    /// if any, before visiting the guard/body.
+    fn bind_case_clause(
+        &mut self,
+        unit: &CompileUnit<'tcx>,
+        node: &HirNode<'tcx>,
+        scopes: &mut BinderScopes<'tcx>,
+        namespace: &'tcx Scope<'tcx>,
+        parent: Option<&Symbol>,
+    ) {
+        if let Some(sn) = node.as_scope()
+            && let Some(scope) = sn.opt_scope()
+        {
+            scopes.push_scope(scope.id());
+
+            if let Some(Some(subject_type)) = self.match_subjects.last() {
+                let subject_type = *subject_type;
+                for &child_id in node.child_ids() {
+                    let child = unit.hir_node(child_id);
+                    if child.kind_id() == LangPython::case_pattern {
+                        bind_pattern_types(unit, scopes, &child, subject_type);
+                    }
+                }
+            }
+
+            self.visit_children(unit, node, scopes, namespace, parent);
+            scopes.pop_scope();
+        } else {
+            self.visit_children(unit, node, scopes, namespace, parent);
+        }
+    }
 }
 
 impl<'tcx> AstVisitorPython<'tcx, BinderScopes<'tcx>> for BinderVisitor<'tcx> {
@@ -73,21 +112,24 @@ impl<'tcx> AstVisitorPython<'tcx, BinderScopes<'tcx>> for BinderVisitor<'tcx> {
         let meta = unit.unit_meta();
 
         if let Some(ref package_name) = meta.package_name
-            && let Some(symbol) = scopes.lookup_symbol(package_name, SymKindSet::from_kind(SymKind::Crate))
+            && let Some(symbol) =
+                scopes.lookup_symbol(package_name, SymKindSet::from_kind(SymKind::Crate))
             && let Some(scope_id) = symbol.opt_scope()
         {
             scopes.push_scope(scope_id);
         }
 
         if let Some(ref module_name) = meta.module_name
-            && let Some(symbol) = scopes.lookup_symbol(module_name, SymKindSet::from_kind(SymKind::Module))
+            && let Some(symbol) =
+                scopes.lookup_symbol(module_name, SymKindSet::from_kind(SymKind::Module))
             && let Some(scope_id) = symbol.opt_scope()
         {
             scopes.push_scope(scope_id);
         }
 
         if let Some(ref file_name) = meta.file_name
-            && let Some(file_sym) = scopes.lookup_symbol(file_name, SymKindSet::from_kind(SymKind::File))
+            && let Some(file_sym) =
+                scopes.lookup_symbol(file_name, SymKindSet::from_kind(SymKind::File))
             && let Some(scope_id) = file_sym.opt_scope()
         {
             scopes.push_scope(scope_id);
@@ -157,7 +199,8 @@ impl<'tcx> AstVisitorPython<'tcx, BinderScopes<'tcx>> for BinderVisitor<'tcx> {
 
             // Handle class inheritance (superclasses)
             if let Some(class_sym) = sn.opt_symbol()
-                && let Some(superclasses) = node.child_by_field(unit, LangPython::field_superclasses)
+                && let Some(superclasses) =
+                    node.child_by_field(unit, LangPython::field_superclasses)
             {
                 for &child_id in superclasses.child_ids() {
                     let child = unit.hir_node(child_id);
@@ -196,7 +239,8 @@ impl<'tcx> AstVisitorPython<'tcx, BinderScopes<'tcx>> for BinderVisitor<'tcx> {
 
             // Handle return type annotation
             if let Some(fn_sym) = sn.opt_symbol()
-                && let Some(return_type_node) = node.child_by_field(unit, LangPython::field_return_type)
+                && let Some(return_type_node) =
+                    node.child_by_field(unit, LangPython::field_return_type)
                 && let Some(return_type) = infer_type(unit, scopes, &return_type_node)
             {
                 fn_sym.set_type_of(return_type.id());
@@ -388,6 +432,31 @@ impl<'tcx> AstVisitorPython<'tcx, BinderScopes<'tcx>> for BinderVisitor<'tcx> {
         }
     }
 
+    /// AST: match subject: case pattern: body ...
+    fn visit_match_statement(
+        &mut self,
+        unit: &CompileUnit<'tcx>,
+        node: &HirNode<'tcx>,
+        scopes: &mut BinderScopes<'tcx>,
+        namespace: &'tcx Scope<'tcx>,
+        parent: Option<&Symbol>,
+    ) {
+        // Resolve the subject first so every case clause's pattern can bind
+        // against it, then make it visible to nested case clauses (which
+        // sit behind an intervening `block` node) via `match_subjects`.
+        let subject_type =
+            if let Some(subject_node) = node.child_by_field(unit, LangPython::field_subject) {
+                self.visit_node(unit, &subject_node, scopes, namespace, parent);
+                infer_type(unit, scopes, &subject_node)
+            } else {
+                None
+            };
+
+        self.match_subjects.push(subject_type);
+        self.visit_children(unit, node, scopes, namespace, parent);
+        self.match_subjects.pop();
+    }
+
     /// AST: case pattern: body
     fn visit_case_clause(
         &mut self,
@@ -397,15 +466,7 @@ impl<'tcx> AstVisitorPython<'tcx, BinderScopes<'tcx>> for BinderVisitor<'tcx> {
         namespace: &'tcx Scope<'tcx>,
         parent: Option<&Symbol>,
     ) {
-        if let Some(sn) = node.as_scope()
-            && let Some(scope) = sn.opt_scope()
-        {
-            scopes.push_scope(scope.id());
-            self.visit_children(unit, node, scopes, namespace, parent);
-            scopes.pop_scope();
-        } else {
-            self.visit_children(unit, node, scopes, namespace, parent);
-        }
+        self.bind_case_clause(unit, node, scopes, namespace, parent);
     }
 
     /// AST: except ExceptionType as name: body
@@ -4,6 +4,7 @@ extern crate llmcc_core;
 mod bind;
 mod collect;
 mod infer;
+mod pattern;
 pub mod token;
 mod util;
 
@@ -11,7 +11,7 @@
 
 use llmcc_core::context::CompileUnit;
 use llmcc_core::ir::HirNode;
-use llmcc_core::symbol::{SymKind, SymKindSet, Symbol};
+use llmcc_core::symbol::{SYM_KIND_ALL, SymKind, SymKindSet, Symbol};
 use llmcc_resolver::BinderScopes;
 
 use crate::token::LangPython;
@@ -52,6 +52,28 @@ pub fn bind_pattern_types<'tcx>(
         LangPython::list => {
             assign_type_to_list_pattern(unit, scopes, pattern, pattern_type);
         }
+        // AST: case_pattern wraps each comma-separated pattern in a `case`
+        // clause; `as_pattern` is a wrapper too - its child sees the same
+        // matched type.
+        LangPython::case_pattern | LangPython::as_pattern => {
+            bind_all_children(unit, scopes, pattern, pattern_type);
+        }
+        // AST: Foo(x) | Bar(x) - each alternative matches the same subject,
+        // but a capture like `x` may resolve to a different type per
+        // alternative.
+        LangPython::union_pattern => {
+            assign_type_to_union_pattern(unit, scopes, pattern, pattern_type);
+        }
+        // AST: Point(px, y=py) - match a class's positional/keyword fields
+        LangPython::class_pattern => {
+            assign_type_to_class_pattern(unit, scopes, pattern, pattern_type);
+        }
+        // AST: {"key": pattern, **rest}
+        LangPython::dict_pattern => {
+            assign_type_to_dict_pattern(unit, scopes, pattern, pattern_type);
+        }
+        // AST: _ - matches anything, binds nothing
+        LangPython::wildcard_pattern => {}
         _ => {
             // Handle other patterns - find and assign to any identifiers
             if let Some(ident) = pattern.find_ident(unit) {
@@ -189,3 +211,364 @@ fn assign_type_to_starred_pattern<'tcx>(
         assign_type_to_ident(unit, scopes, ident, container_type);
     }
 }
+
+/// Recurse into every non-trivia child with the same matched type. Used for
+/// wrapper-like patterns (`case_pattern`, `as_pattern`) where each child is
+/// matching against the same subject; `union_pattern` goes through
+/// [`assign_type_to_union_pattern`] instead.
+fn bind_all_children<'tcx>(
+    unit: &CompileUnit<'tcx>,
+    scopes: &mut BinderScopes<'tcx>,
+    pattern: &HirNode<'tcx>,
+    pattern_type: &'tcx Symbol,
+) {
+    for child in pattern.children(unit) {
+        if !child.is_trivia() {
+            bind_pattern_types(unit, scopes, &child, pattern_type);
+        }
+    }
+}
+
+/// Assign types for a `union_pattern` (`Foo(x) | Bar(x)`): a capture
+/// present in every alternative gets that alternative's type only if all
+/// alternatives agree on it. Since there's no union type to assign when
+/// they disagree, such a capture falls back to the matched subject's own
+/// type instead of whichever alternative happened to bind it first.
+fn assign_type_to_union_pattern<'tcx>(
+    unit: &CompileUnit<'tcx>,
+    scopes: &mut BinderScopes<'tcx>,
+    pattern: &HirNode<'tcx>,
+    pattern_type: &'tcx Symbol,
+) {
+    let alternatives: Vec<_> = pattern
+        .children(unit)
+        .into_iter()
+        .filter(|c| !c.is_trivia())
+        .collect();
+
+    // Figure out what type each alternative would give its captures before
+    // actually assigning anything, so we can tell agreement from a race.
+    let per_alternative: Vec<Vec<(String, &'tcx Symbol)>> = alternatives
+        .iter()
+        .map(|alt| {
+            let mut captures = Vec::new();
+            collect_pattern_captures(unit, scopes, alt, pattern_type, &mut captures);
+            captures
+        })
+        .collect();
+
+    for alt in &alternatives {
+        bind_pattern_types(unit, scopes, alt, pattern_type);
+    }
+
+    let Some(first) = per_alternative.first() else {
+        return;
+    };
+
+    'names: for first_entry in first {
+        let name = &first_entry.0;
+        let mut agreed: Option<&Symbol> = None;
+        for alt in &per_alternative {
+            let Some(entry) = alt.iter().find(|e| &e.0 == name) else {
+                // Not every alternative binds this name - leave whatever
+                // the alternative that does bind it assigned.
+                continue 'names;
+            };
+            let ty = entry.1;
+            match agreed {
+                None => agreed = Some(ty),
+                Some(prev) if std::ptr::eq(prev, ty) => {}
+                Some(_) => {
+                    if let Some(symbol) =
+                        scopes.lookup_symbol(name, SymKindSet::from_kind(SymKind::Variable))
+                    {
+                        symbol.set_type_of(pattern_type.id());
+                    }
+                    continue 'names;
+                }
+            }
+        }
+    }
+}
+
+/// Dry-run counterpart of [`bind_pattern_types`] used by
+/// [`assign_type_to_union_pattern`] to discover, without mutating any
+/// symbol, which type each alternative would give its captures.
+fn collect_pattern_captures<'tcx>(
+    unit: &CompileUnit<'tcx>,
+    scopes: &BinderScopes<'tcx>,
+    pattern: &HirNode<'tcx>,
+    pattern_type: &'tcx Symbol,
+    out: &mut Vec<(String, &'tcx Symbol)>,
+) {
+    if let Some(ident) = pattern.as_ident() {
+        out.push((ident.name.clone(), pattern_type));
+        return;
+    }
+
+    match pattern.kind_id() {
+        LangPython::case_pattern | LangPython::as_pattern | LangPython::union_pattern => {
+            for child in pattern.children(unit) {
+                if !child.is_trivia() {
+                    collect_pattern_captures(unit, scopes, &child, pattern_type, out);
+                }
+            }
+        }
+        LangPython::class_pattern => {
+            collect_class_pattern_captures(unit, scopes, pattern, pattern_type, out);
+        }
+        LangPython::dict_pattern => {
+            collect_dict_pattern_captures(unit, scopes, pattern, pattern_type, out);
+        }
+        LangPython::wildcard_pattern => {}
+        _ => {
+            if let Some(ident) = pattern.find_ident(unit) {
+                out.push((ident.name.clone(), pattern_type));
+            } else {
+                for child in pattern.children(unit) {
+                    collect_pattern_captures(unit, scopes, &child, pattern_type, out);
+                }
+            }
+        }
+    }
+}
+
+/// Dry-run counterpart of [`assign_type_to_class_pattern`].
+fn collect_class_pattern_captures<'tcx>(
+    unit: &CompileUnit<'tcx>,
+    scopes: &BinderScopes<'tcx>,
+    pattern: &HirNode<'tcx>,
+    pattern_type: &'tcx Symbol,
+    out: &mut Vec<(String, &'tcx Symbol)>,
+) {
+    let children: Vec<_> = pattern
+        .children(unit)
+        .into_iter()
+        .filter(|c| !c.is_trivia())
+        .collect();
+
+    let Some((class_node, fields)) = children.split_first() else {
+        return;
+    };
+
+    let class_sym = class_node
+        .find_ident(unit)
+        .and_then(|ident| {
+            ident
+                .opt_symbol()
+                .or_else(|| scopes.lookup_symbol(ident.name.as_str(), SYM_KIND_ALL))
+        })
+        .unwrap_or(pattern_type);
+
+    let match_args = match_args_names(unit, scopes, class_sym);
+    let mut positional_index = 0;
+
+    for field in fields {
+        if field.kind_id() == LangPython::keyword_pattern {
+            let Some(name_ident) = field
+                .child_by_field(unit, LangPython::field_name)
+                .and_then(|n| n.find_ident(unit))
+            else {
+                continue;
+            };
+            let Some(value_node) = field.child_by_field(unit, LangPython::field_value) else {
+                continue;
+            };
+            let field_type =
+                field_type_of(unit, scopes, class_sym, &name_ident.name).unwrap_or(class_sym);
+            collect_pattern_captures(unit, scopes, &value_node, field_type, out);
+            continue;
+        }
+
+        let field_type = match_args
+            .get(positional_index)
+            .and_then(|name| field_type_of(unit, scopes, class_sym, name))
+            .unwrap_or(class_sym);
+        positional_index += 1;
+
+        collect_pattern_captures(unit, scopes, field, field_type, out);
+    }
+}
+
+/// Dry-run counterpart of [`assign_type_to_dict_pattern`].
+fn collect_dict_pattern_captures<'tcx>(
+    unit: &CompileUnit<'tcx>,
+    scopes: &BinderScopes<'tcx>,
+    pattern: &HirNode<'tcx>,
+    dict_type: &'tcx Symbol,
+    out: &mut Vec<(String, &'tcx Symbol)>,
+) {
+    let value_type = if let Some(nested) = dict_type.nested_types()
+        && let Some(last) = nested.last()
+    {
+        unit.opt_get_symbol(*last).unwrap_or(dict_type)
+    } else {
+        dict_type
+    };
+
+    for child in pattern.children(unit) {
+        if child.is_trivia() {
+            continue;
+        }
+        if child.kind_id() == LangPython::dictionary_splat_pattern {
+            collect_pattern_captures(unit, scopes, &child, dict_type, out);
+            continue;
+        }
+        if let Some(value_node) = child.child_by_field(unit, LangPython::field_value) {
+            collect_pattern_captures(unit, scopes, &value_node, value_type, out);
+        } else {
+            collect_pattern_captures(unit, scopes, &child, value_type, out);
+        }
+    }
+}
+
+/// Assign types to a class pattern's fields: `Point(px, y=py)` resolves the
+/// `Point` class, maps positional sub-patterns to `__match_args__` (in
+/// declaration order) and keyword sub-patterns to the named attribute, and
+/// recurses into each sub-pattern with that field's declared type.
+fn assign_type_to_class_pattern<'tcx>(
+    unit: &CompileUnit<'tcx>,
+    scopes: &mut BinderScopes<'tcx>,
+    pattern: &HirNode<'tcx>,
+    pattern_type: &'tcx Symbol,
+) {
+    let children: Vec<_> = pattern
+        .children(unit)
+        .into_iter()
+        .filter(|c| !c.is_trivia())
+        .collect();
+
+    let Some((class_node, fields)) = children.split_first() else {
+        return;
+    };
+
+    let class_sym = class_node
+        .find_ident(unit)
+        .and_then(|ident| {
+            ident
+                .opt_symbol()
+                .or_else(|| scopes.lookup_symbol(ident.name, SYM_KIND_ALL))
+        })
+        .unwrap_or(pattern_type);
+
+    let match_args = match_args_names(unit, scopes, class_sym);
+    let mut positional_index = 0;
+
+    for field in fields {
+        if field.kind_id() == LangPython::keyword_pattern {
+            assign_type_to_keyword_pattern(unit, scopes, field, class_sym);
+            continue;
+        }
+
+        let field_type = match_args
+            .get(positional_index)
+            .and_then(|name| field_type_of(unit, scopes, class_sym, name))
+            .unwrap_or(class_sym);
+        positional_index += 1;
+
+        bind_pattern_types(unit, scopes, field, field_type);
+    }
+}
+
+/// Assign a type to a class pattern's `name=sub_pattern` keyword field.
+fn assign_type_to_keyword_pattern<'tcx>(
+    unit: &CompileUnit<'tcx>,
+    scopes: &mut BinderScopes<'tcx>,
+    pattern: &HirNode<'tcx>,
+    class_sym: &'tcx Symbol,
+) {
+    let Some(name_ident) = pattern
+        .child_by_field(unit, LangPython::field_name)
+        .and_then(|n| n.find_ident(unit))
+    else {
+        return;
+    };
+    let Some(value_node) = pattern.child_by_field(unit, LangPython::field_value) else {
+        return;
+    };
+
+    let field_type = field_type_of(unit, scopes, class_sym, name_ident.name).unwrap_or(class_sym);
+    bind_pattern_types(unit, scopes, &value_node, field_type);
+}
+
+/// Look up `class_sym`'s attribute `name` and return its declared type.
+fn field_type_of<'tcx>(
+    unit: &CompileUnit<'tcx>,
+    scopes: &BinderScopes<'tcx>,
+    class_sym: &'tcx Symbol,
+    name: &str,
+) -> Option<&'tcx Symbol> {
+    scopes
+        .lookup_member_symbol(class_sym, name, None)
+        .and_then(|member| member.type_of())
+        .and_then(|id| unit.opt_get_symbol(id))
+}
+
+/// Resolve `__match_args__`'s string literals in declaration order, if the
+/// class defines it, the same way Python itself maps a class pattern's
+/// positional sub-patterns to named attributes.
+fn match_args_names<'tcx>(
+    unit: &CompileUnit<'tcx>,
+    scopes: &BinderScopes<'tcx>,
+    class_sym: &'tcx Symbol,
+) -> Vec<String> {
+    let Some(match_args_sym) = scopes.lookup_member_symbol(class_sym, "__match_args__", None)
+    else {
+        return Vec::new();
+    };
+    let Some(parent_id) = unit.hir_node(match_args_sym.owner()).parent() else {
+        return Vec::new();
+    };
+    let assignment = unit.hir_node(parent_id);
+    let Some(right) = assignment.child_by_field(unit, LangPython::field_right) else {
+        return Vec::new();
+    };
+
+    right
+        .children(unit)
+        .into_iter()
+        .filter(|c| c.kind_id() == LangPython::string)
+        .filter_map(|c| string_literal_value(unit, &c))
+        .collect()
+}
+
+/// Extract a string literal node's content with surrounding quotes stripped.
+fn string_literal_value<'tcx>(unit: &CompileUnit<'tcx>, node: &HirNode<'tcx>) -> Option<String> {
+    node.children(unit).into_iter().find_map(|child| {
+        child
+            .as_text()
+            .map(|t| t.text().trim_matches(['\'', '"']).to_string())
+    })
+}
+
+/// Assign types to a mapping pattern's entries: value sub-patterns get the
+/// dict's value type, and a `**rest` capture gets the dict type itself.
+fn assign_type_to_dict_pattern<'tcx>(
+    unit: &CompileUnit<'tcx>,
+    scopes: &mut BinderScopes<'tcx>,
+    pattern: &HirNode<'tcx>,
+    dict_type: &'tcx Symbol,
+) {
+    let value_type = if let Some(nested) = dict_type.nested_types()
+        && let Some(last) = nested.last()
+    {
+        unit.opt_get_symbol(*last).unwrap_or(dict_type)
+    } else {
+        dict_type
+    };
+
+    for child in pattern.children(unit) {
+        if child.is_trivia() {
+            continue;
+        }
+        if child.kind_id() == LangPython::dictionary_splat_pattern {
+            bind_pattern_types(unit, scopes, &child, dict_type);
+            continue;
+        }
+        if let Some(value_node) = child.child_by_field(unit, LangPython::field_value) {
+            bind_pattern_types(unit, scopes, &value_node, value_type);
+        } else {
+            bind_pattern_types(unit, scopes, &child, value_type);
+        }
+    }
+}
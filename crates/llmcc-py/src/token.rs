@@ -1,7 +1,9 @@
 use llmcc_core::LanguageTraitImpl;
 use llmcc_core::graph_builder::BlockKind;
 use llmcc_core::ir::{HirKind, HirNode};
-use llmcc_core::lang_def::{LanguageTrait, ParseNode, ParseTree, TreeSitterParseTree};
+use llmcc_core::lang_def::{
+    Diagnostic, LanguageTrait, ParseNode, ParseTree, Severity, TreeSitterParseTree,
+};
 use llmcc_core::scope::{Scope, ScopeStack};
 use llmcc_core::symbol::{SymKind, Symbol};
 use llmcc_core::{CompileCtxt, CompileUnit};
@@ -75,7 +77,7 @@ impl LanguageTraitImpl for LangPython {
             let mut parser = parser.borrow_mut();
             let bytes = text.as_ref();
             let tree = parser.parse(bytes, None)?;
-            Some(Box::new(TreeSitterParseTree { tree }) as Box<dyn ParseTree>)
+            Some(Box::new(TreeSitterParseTree::new(tree, bytes)) as Box<dyn ParseTree>)
         })
     }
 
@@ -83,6 +85,16 @@ impl LanguageTraitImpl for LangPython {
         &["py", "pyi"]
     }
 
+    /// Flag malformed string escapes and mixed tab/space indentation that
+    /// the grammar itself can't reject.
+    fn validate_impl(tree: &dyn ParseTree, source: &[u8], sink: &mut Vec<Diagnostic>) {
+        let mut opaque_ranges = Vec::new();
+        if let Some(root) = tree.root_node() {
+            validate_node(root.as_ref(), source, sink, &mut opaque_ranges);
+        }
+        validate_indentation(source, &opaque_ranges, sink);
+    }
+
     /// Check if the given parse node is a Python test decorator.
     /// Detects: @pytest.mark.*, @unittest.*, etc.
     fn is_test_attribute_impl(node: &dyn ParseNode, source: &[u8]) -> bool {
@@ -136,3 +148,94 @@ impl LanguageTraitImpl for LangPython {
         }
     }
 }
+
+/// Escape characters Python's string grammar actually recognizes after a
+/// backslash (octal digits, `x`, and the unicode escapes are approximated by
+/// checking only the first following character).
+const VALID_ESCAPES: &[u8] = b"\n\\'\"abfnrtv01234567xNuU";
+
+/// Walk the tree once, both checking string escapes and recording every
+/// string/comment's byte range as `opaque_ranges` so [`validate_indentation`]
+/// knows not to look inside them.
+fn validate_node(
+    node: &dyn ParseNode,
+    source: &[u8],
+    sink: &mut Vec<Diagnostic>,
+    opaque_ranges: &mut Vec<(usize, usize)>,
+) {
+    if node.kind_id() == LangPython::string || node.is_extra() {
+        opaque_ranges.push((node.start_byte(), node.end_byte()));
+    }
+    if node.kind_id() == LangPython::string {
+        validate_string_escapes(node, source, sink);
+    }
+    for i in 0..node.child_count() {
+        if let Some(child) = node.child(i) {
+            validate_node(child.as_ref(), source, sink, opaque_ranges);
+        }
+    }
+}
+
+fn validate_string_escapes(node: &dyn ParseNode, source: &[u8], sink: &mut Vec<Diagnostic>) {
+    let text = node.text(source);
+    let bytes = text.as_bytes();
+
+    // Raw strings (`r"..."`, `rb"..."`, `br"..."`, `rf"..."`, ...) don't
+    // interpret backslash escapes at all, so a backslash followed by
+    // anything is always legal there - skip the scan entirely rather than
+    // flagging routine regex literals like `r"\d+"`.
+    let prefix_len = bytes
+        .iter()
+        .position(|&b| b == b'\'' || b == b'"')
+        .unwrap_or(0);
+    if text[..prefix_len].contains(['r', 'R']) {
+        return;
+    }
+
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'\\' && i + 1 < bytes.len() {
+            let next = bytes[i + 1];
+            if !VALID_ESCAPES.contains(&next) {
+                let start = node.start_byte() + i;
+                sink.push(Diagnostic {
+                    range: (start, start + 2),
+                    severity: Severity::Warning,
+                    message: format!("unsupported escape sequence '\\{}'", next as char),
+                });
+            }
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+}
+
+fn validate_indentation(
+    source: &[u8],
+    opaque_ranges: &[(usize, usize)],
+    sink: &mut Vec<Diagnostic>,
+) {
+    let mut offset = 0;
+    for line in source.split(|&b| b == b'\n') {
+        let inside_opaque_range = opaque_ranges
+            .iter()
+            .any(|&(start, end)| offset >= start && offset < end);
+
+        if !inside_opaque_range {
+            let mut end = 0;
+            while end < line.len() && (line[end] == b' ' || line[end] == b'\t') {
+                end += 1;
+            }
+            let leading = &line[..end];
+            if leading.contains(&b' ') && leading.contains(&b'\t') {
+                sink.push(Diagnostic {
+                    range: (offset, offset + end),
+                    severity: Severity::Warning,
+                    message: "line mixes tabs and spaces in indentation".to_string(),
+                });
+            }
+        }
+        offset += line.len() + 1;
+    }
+}
@@ -0,0 +1,91 @@
+use llmcc_core::context::CompileCtxt;
+use llmcc_core::{IrBuildOption, build_llmcc_ir};
+use llmcc_py::token::LangPython;
+use llmcc_resolver::{ResolverOption, bind_symbols_with, collect_symbols_with};
+
+/// Parse, build IR, collect, and bind a standalone source snippet, exercising
+/// the same pipeline `llmcc-cli` drives - just enough to make sure binding a
+/// `match`/`case` statement doesn't panic.
+fn bind_from_source(source: &str) {
+    let sources = vec![source.as_bytes().to_vec()];
+    let cc = CompileCtxt::from_sources::<LangPython>(&sources);
+    build_llmcc_ir::<LangPython>(&cc, IrBuildOption::default()).expect("build IR");
+
+    let resolver_option = ResolverOption::default();
+    let globals = collect_symbols_with::<LangPython>(&cc, &resolver_option);
+    bind_symbols_with::<LangPython>(&cc, globals, &resolver_option);
+}
+
+#[test]
+fn bind_class_pattern_positional_and_keyword_fields() {
+    let source = r#"
+class Point:
+    __match_args__ = ("x", "y")
+
+    def __init__(self, x, y):
+        self.x = x
+        self.y = y
+
+def describe(p):
+    match p:
+        case Point(x, y=y):
+            print(x, y)
+        case _:
+            pass
+"#;
+    bind_from_source(source);
+}
+
+#[test]
+fn bind_dict_pattern_with_rest_capture() {
+    let source = r#"
+def describe(d):
+    match d:
+        case {"key": value, **rest}:
+            print(value, rest)
+        case _:
+            pass
+"#;
+    bind_from_source(source);
+}
+
+#[test]
+fn bind_union_pattern_shares_a_capture_across_alternatives() {
+    // `x` is captured by both `Foo(x)` and `Bar(x)` - the capture must be
+    // bound once without panicking, regardless of whether the two classes
+    // agree on `x`'s type.
+    let source = r#"
+class Foo:
+    __match_args__ = ("x",)
+
+    def __init__(self, x):
+        self.x = x
+
+class Bar:
+    __match_args__ = ("x",)
+
+    def __init__(self, x):
+        self.x = x
+
+def describe(value):
+    match value:
+        case Foo(x) | Bar(x):
+            print(x)
+        case _:
+            pass
+"#;
+    bind_from_source(source);
+}
+
+#[test]
+fn bind_nested_as_and_wildcard_patterns() {
+    let source = r#"
+def describe(value):
+    match value:
+        case [first, *_rest] as whole:
+            print(first, whole)
+        case _:
+            pass
+"#;
+    bind_from_source(source);
+}
@@ -1,9 +1,12 @@
-use std::collections::{BTreeSet, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::ops::ControlFlow;
 use std::path::{Path, PathBuf};
 
+use serde::Serialize;
+
 use llmcc_core::context::CompileUnit;
-use llmcc_core::ir::HirNode;
-use llmcc_core::symbol::{Scope, ScopeStack, Symbol, SymbolKind};
+use llmcc_core::ir::{HirId, HirNode};
+use llmcc_core::symbol::{Scope, ScopeStack, SymId, Symbol, SymbolKind};
 
 use llmcc_descriptor::{CallChain, CallChainRoot, TypeExpr};
 use llmcc_resolver::{BinderCore, CollectedSymbols, CollectionResult};
@@ -13,6 +16,18 @@ use crate::token::{AstVisitorPython, LangPython};
 #[derive(Debug, Default)]
 pub struct BindingResult {
     pub calls: Vec<CallBinding>,
+    /// Definitions and resolved references gathered alongside `calls`, with
+    /// source spans - a stable def/ref graph external tooling (indexers,
+    /// LSP backends) can consume instead of reconstructing one from
+    /// `Symbol::depends`.
+    pub cross_refs: CrossRefIndex,
+    /// Names the binder couldn't resolve - an unresolved call target, type
+    /// expression, or import path - instead of those failures being silently
+    /// dropped. See `has_errors`.
+    pub diagnostics: Vec<BindingDiagnostic>,
+    /// Every textual occurrence of a resolved symbol, keyed by `SymId` - see
+    /// `references_to`.
+    pub references: Vec<Reference>,
 }
 
 #[derive(Debug, Clone)]
@@ -21,24 +36,288 @@ pub struct CallBinding {
     pub target: String,
 }
 
-#[derive(Debug)]
-struct SymbolBinder<'tcx, 'a> {
+/// A name `SymbolBinder` failed to resolve against the current scope chain,
+/// tagged with the originating node and the unresolved text.
+#[derive(Debug, Clone)]
+pub struct BindingDiagnostic {
+    pub hir_id: HirId,
+    pub kind: BindingDiagnosticKind,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BindingDiagnosticKind {
+    UnresolvedCall,
+    UnresolvedType,
+    UnresolvedImport,
+}
+
+impl BindingResult {
+    /// Build a queryable call graph over `self.calls`, indexed by FQN. Build
+    /// once and reuse - each query is a `HashMap` lookup instead of a scan of
+    /// the flat `Vec<CallBinding>`.
+    pub fn call_graph(&self) -> CallGraph {
+        CallGraph::build(&self.calls)
+    }
+
+    /// Whether binding this unit left any name unresolved.
+    pub fn has_errors(&self) -> bool {
+        !self.diagnostics.is_empty()
+    }
+
+    /// Every recorded occurrence of `symbol` - the basis for safe rename and
+    /// reference-finding.
+    pub fn references_to(&self, symbol: SymId) -> Vec<&Reference> {
+        self.references
+            .iter()
+            .filter(|reference| reference.target == symbol)
+            .collect()
+    }
+}
+
+/// Forward (`callees_of`) and reverse (`callers_of`) adjacency over a set of
+/// `CallBinding`s, keyed by FQN - makes the binder's output usable for
+/// impact analysis and call-graph navigation without re-indexing per query.
+#[derive(Debug, Default)]
+pub struct CallGraph {
+    callees: HashMap<String, Vec<String>>,
+    callers: HashMap<String, Vec<String>>,
+}
+
+impl CallGraph {
+    pub fn build(calls: &[CallBinding]) -> Self {
+        let mut callees: HashMap<String, Vec<String>> = HashMap::new();
+        let mut callers: HashMap<String, Vec<String>> = HashMap::new();
+
+        for call in calls {
+            callees
+                .entry(call.caller.clone())
+                .or_default()
+                .push(call.target.clone());
+            callers
+                .entry(call.target.clone())
+                .or_default()
+                .push(call.caller.clone());
+        }
+
+        Self { callees, callers }
+    }
+
+    /// FQNs `fqn` calls directly.
+    pub fn callees_of(&self, fqn: &str) -> &[String] {
+        self.callees.get(fqn).map_or(&[], Vec::as_slice)
+    }
+
+    /// FQNs that call `fqn` directly.
+    pub fn callers_of(&self, fqn: &str) -> &[String] {
+        self.callers.get(fqn).map_or(&[], Vec::as_slice)
+    }
+
+    /// Transitive closure of `callees_of` starting at `fqn` (`fqn` itself is
+    /// not included), visiting each FQN at most once so a cycle in the call
+    /// graph terminates the walk instead of looping forever.
+    pub fn reachable_from(&self, fqn: &str) -> Vec<String> {
+        let mut visited: HashSet<String> = HashSet::from([fqn.to_string()]);
+        let mut stack: Vec<String> = vec![fqn.to_string()];
+        let mut result = Vec::new();
+
+        while let Some(current) = stack.pop() {
+            for callee in self.callees_of(&current) {
+                if visited.insert(callee.clone()) {
+                    result.push(callee.clone());
+                    stack.push(callee.clone());
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// A symbol defined in this unit: its FQN, kind, and the byte span of the
+/// defining node (function/class/module).
+#[derive(Debug, Clone, Serialize)]
+pub struct SymbolDef {
+    pub fqn: String,
+    pub kind: String,
+    pub span: (usize, usize),
+}
+
+/// A resolved reference: `ref_fqn` is the symbol `record_segments_dependency`/
+/// `handle_symbol_segments` resolved it to, `ref_span` is the byte span of
+/// the referencing token (the call/import expression), and `def_fqn` is the
+/// enclosing symbol doing the referencing (`"<module>"` at module scope).
+#[derive(Debug, Clone, Serialize)]
+pub struct CrossRef {
+    pub def_fqn: String,
+    pub ref_fqn: String,
+    pub ref_span: (usize, usize),
+    pub ref_kind: String,
+}
+
+/// Def/ref graph for one compile unit, serializable to JSON for external
+/// tooling. Only references whose originating token span is known are
+/// recorded - see `SymbolBinder::current_ref_span`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CrossRefIndex {
+    pub defs: Vec<SymbolDef>,
+    pub refs: Vec<CrossRef>,
+}
+
+impl CrossRefIndex {
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+}
+
+/// One textual occurrence of a symbol, keyed by `SymId` rather than `CrossRef`'s
+/// fqn string - recorded whenever `visit_call`, a `visit_assignment` type
+/// annotation, or `visit_import_*` resolves a name to a scope entry. The
+/// basis for safe rename (rewrite every span bound to one symbol) and
+/// reference-finding.
+#[derive(Debug, Clone)]
+pub struct Reference {
+    pub target: SymId,
+    pub hir_id: HirId,
+    pub span: (usize, usize),
+}
+
+/// Which resolution context a segment lookup is happening in. Python lets a
+/// class and a function (or a module attribute) share a name, so a type
+/// annotation (`x: Foo`) and a call (`Foo()`) need different priority orders
+/// instead of one fixed list deciding both by whichever symbol happens to
+/// come first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ResolutionNs {
+    /// Type-position lookup (annotations, base classes): prefer `Struct`/`Enum`.
+    TypeNs,
+    /// Value-position lookup (calls): prefer `Function`, then a struct's
+    /// constructor.
+    ValueNs,
+}
+
+impl ResolutionNs {
+    fn priority(self) -> &'static [SymbolKind] {
+        match self {
+            ResolutionNs::TypeNs => &[SymbolKind::Struct, SymbolKind::Enum, SymbolKind::Module],
+            ResolutionNs::ValueNs => {
+                &[SymbolKind::Function, SymbolKind::Struct, SymbolKind::Module]
+            }
+        }
+    }
+}
+
+/// One thing the binder just recorded, passed to a `find_binding_where`
+/// predicate so it can decide whether to stop the walk here.
+pub enum BindEvent<'e> {
+    Call { caller: &'e str, target: &'e str },
+    Import { path: &'e str },
+}
+
+/// The symbol a `resolve_at` cursor query resolved to.
+#[derive(Debug, Clone)]
+pub struct PositionBinding {
+    pub fqn: String,
+    pub kind: SymbolKind,
+}
+
+impl PositionBinding {
+    fn from_symbol(symbol: &Symbol) -> Self {
+        Self {
+            fqn: symbol.fqn_name.read().clone(),
+            kind: symbol.kind(),
+        }
+    }
+}
+
+struct SymbolBinder<'tcx, 'a, B = std::convert::Infallible> {
     core: BinderCore<'tcx, 'a>,
     calls: Vec<CallBinding>,
     module_imports: Vec<&'tcx Symbol>,
+    defs: Vec<SymbolDef>,
+    refs: Vec<CrossRef>,
+    diagnostics: Vec<BindingDiagnostic>,
+    references: Vec<Reference>,
+    /// Byte span of the token currently being resolved (a call expression or
+    /// import path), if any - threaded into `add_symbol_relation` so it can
+    /// emit a `CrossRef` alongside the dependency edge it already records.
+    current_ref_span: Option<(usize, usize)>,
+    /// `HirId` of the node currently being resolved, alongside
+    /// `current_ref_span` - threaded into `add_symbol_relation` so it can
+    /// also emit a `Reference`.
+    current_ref_hir_id: Option<HirId>,
+    current_ref_kind: &'static str,
+    /// Flow-insensitive local variable -> class environment, one frame per
+    /// pushed scope (function/class/block). Populated from typed parameters
+    /// and `x = Class(...)` constructor assignments, consulted by
+    /// `resolve_method_from_chain` to resolve `obj.method()` calls where
+    /// `obj` isn't `self`. A later assignment shadows an earlier one within
+    /// the same frame, matching the flow-insensitive, last-write-wins model.
+    local_types: Vec<HashMap<String, &'tcx Symbol>>,
+    /// Bounded-query predicate for `find_binding_where`, consulted right
+    /// after a call or import is recorded - `Some(b)` stops the walk there
+    /// and surfaces `b`. `None` for a normal full-file `bind_symbols` walk,
+    /// which never breaks (hence the `Infallible` default for `B`).
+    predicate: Option<Box<dyn FnMut(&BindEvent<'_>) -> Option<B> + 'a>>,
+    /// Cursor byte offset for a `resolve_at` query - when set, `visit_children`
+    /// descends only into the child whose span contains it instead of
+    /// visiting the whole subtree, and `visit_call`/`visit_assignment`/
+    /// `visit_import_*` resolve a symbol into `position_result` instead of
+    /// recording their usual calls/defs/refs. `None` for a normal walk.
+    target_pos: Option<usize>,
+    /// Result of a `resolve_at` query - the innermost matching node along
+    /// the narrowed path overwrites shallower ones, so the smallest
+    /// enclosing span wins.
+    position_result: Option<PositionBinding>,
 }
 
-impl<'tcx, 'a> SymbolBinder<'tcx, 'a> {
+impl<'tcx, 'a, B> SymbolBinder<'tcx, 'a, B> {
     pub fn new(
         unit: CompileUnit<'tcx>,
         globals: &'tcx Scope<'tcx>,
         collection: &'a CollectionResult,
+        predicate: Option<Box<dyn FnMut(&BindEvent<'_>) -> Option<B> + 'a>>,
     ) -> Self {
         Self {
             core: BinderCore::new(unit, globals, collection),
             calls: Vec::new(),
             module_imports: Vec::new(),
+            defs: Vec::new(),
+            refs: Vec::new(),
+            diagnostics: Vec::new(),
+            references: Vec::new(),
+            current_ref_span: None,
+            current_ref_hir_id: None,
+            current_ref_kind: "reference",
+            local_types: Vec::new(),
+            predicate,
+            target_pos: None,
+            position_result: None,
+        }
+    }
+
+    /// Consult the `find_binding_where` predicate (if any) about `event`,
+    /// breaking the walk out with its value on a match.
+    fn check_predicate(&mut self, event: &BindEvent<'_>) -> ControlFlow<B, ()> {
+        if let Some(predicate) = self.predicate.as_mut() {
+            if let Some(value) = predicate(event) {
+                return ControlFlow::Break(value);
+            }
         }
+        ControlFlow::Continue(())
+    }
+
+    fn record_diagnostic(
+        &mut self,
+        hir_id: HirId,
+        kind: BindingDiagnosticKind,
+        text: impl Into<String>,
+    ) {
+        self.diagnostics.push(BindingDiagnostic {
+            hir_id,
+            kind,
+            text: text.into(),
+        });
     }
 
     fn unit(&self) -> CompileUnit<'tcx> {
@@ -145,13 +424,145 @@ impl<'tcx, 'a> SymbolBinder<'tcx, 'a> {
 
         let _ = self.scopes_mut().insert_symbol(symbol, true);
         scope.set_symbol(Some(symbol));
+        self.record_def(symbol, node);
         Some(symbol)
     }
 
+    /// Run `f` with `current_ref_span`/`current_ref_hir_id`/`current_ref_kind`
+    /// set to `span`/`hir_id`, restoring the previous context afterwards so
+    /// nested references (e.g. a call nested in a call's arguments, or a
+    /// segment within a dotted path) each record their own span.
+    fn with_ref_context<R>(
+        &mut self,
+        span: (usize, usize),
+        hir_id: HirId,
+        kind: &'static str,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        let prev_span = self.current_ref_span.replace(span);
+        let prev_hir_id = self.current_ref_hir_id.replace(hir_id);
+        let prev_kind = std::mem::replace(&mut self.current_ref_kind, kind);
+        let result = f(self);
+        self.current_ref_span = prev_span;
+        self.current_ref_hir_id = prev_hir_id;
+        self.current_ref_kind = prev_kind;
+        result
+    }
+
+    /// Record that `symbol` is defined here, as of `node`'s span.
+    fn record_def(&mut self, symbol: &Symbol, node: &HirNode<'tcx>) {
+        self.defs.push(SymbolDef {
+            fqn: symbol.fqn_name.read().clone(),
+            kind: format!("{:?}", symbol.kind()),
+            span: (node.start_byte(), node.end_byte()),
+        });
+    }
+
+    /// Push a fresh local-type frame, returning the depth to restore via
+    /// `pop_local_scope`. Call alongside `scopes_mut().push_with_symbol`.
+    fn push_local_scope(&mut self) -> usize {
+        let depth = self.local_types.len();
+        self.local_types.push(HashMap::new());
+        depth
+    }
+
+    /// Drop local-type frames pushed since `depth`. Call alongside
+    /// `scopes_mut().pop_until`.
+    fn pop_local_scope(&mut self, depth: usize) {
+        self.local_types.truncate(depth);
+    }
+
+    /// Bind `name` to `class_symbol` in the innermost local-type frame,
+    /// shadowing any earlier binding (flow-insensitive, last-write-wins).
+    fn bind_local_type(&mut self, name: &str, class_symbol: &'tcx Symbol) {
+        if let Some(frame) = self.local_types.last_mut() {
+            frame.insert(name.to_string(), class_symbol);
+        }
+    }
+
+    /// Look up `name`'s class, innermost frame first.
+    fn lookup_local_type(&self, name: &str) -> Option<&'tcx Symbol> {
+        self.local_types
+            .iter()
+            .rev()
+            .find_map(|frame| frame.get(name).copied())
+    }
+
+    /// Resolve a `TypeExpr::Path`'s segments to the class it denotes, if any.
+    fn resolve_type_expr_class(&mut self, expr: &TypeExpr) -> Option<&'tcx Symbol> {
+        match expr {
+            TypeExpr::Path { segments, .. } if !segments.is_empty() => self
+                .core
+                .lookup_segments_with_priority(segments, &[SymbolKind::Struct], None),
+            _ => None,
+        }
+    }
+
+    /// `x = Name(...)` / `x = pkg.Class(...)`: if the right-hand side is a
+    /// constructor call, bind the left-hand identifier to the resolved class
+    /// in the local-type environment, so later `x.method()` calls resolve.
+    fn bind_local_type_from_constructor(&mut self, node: &HirNode<'tcx>) {
+        let Some(name_node) = node.opt_child_by_field(self.unit(), LangPython::field_left) else {
+            return;
+        };
+        let Some(ident) = name_node.as_ident() else {
+            return;
+        };
+        let var_name = ident.name.clone();
+
+        for child_id in node.children() {
+            let child = self.unit().hir_node(*child_id);
+            if child.kind_id() != LangPython::call {
+                continue;
+            }
+
+            let Some(descriptor) = self.collection().calls.find(child.hir_id()) else {
+                continue;
+            };
+
+            if let llmcc_descriptor::CallTarget::Symbol(symbol) = &descriptor.target {
+                if symbol.kind == llmcc_descriptor::CallKind::Constructor {
+                    let mut segments = symbol.qualifiers.clone();
+                    segments.push(symbol.name.clone());
+                    if let Some(class_symbol) = self.core.lookup_segments_with_priority(
+                        &segments,
+                        &[SymbolKind::Struct],
+                        None,
+                    ) {
+                        self.bind_local_type(&var_name, class_symbol);
+                    }
+                }
+            }
+            break;
+        }
+    }
+
     fn add_symbol_relation(&mut self, symbol: Option<&'tcx Symbol>) {
         self.core.add_symbol_dependency(symbol);
 
         let Some(target) = symbol else { return };
+
+        if let Some(ref_span) = self.current_ref_span {
+            let def_fqn = self
+                .current_symbol()
+                .map(|s| s.fqn_name.read().clone())
+                .unwrap_or_else(|| "<module>".to_string());
+            self.refs.push(CrossRef {
+                def_fqn,
+                ref_fqn: target.fqn_name.read().clone(),
+                ref_span,
+                ref_kind: self.current_ref_kind.to_string(),
+            });
+
+            if let Some(hir_id) = self.current_ref_hir_id {
+                self.references.push(Reference {
+                    target: target.id,
+                    hir_id,
+                    span: ref_span,
+                });
+            }
+        }
+
         let Some(current) = self.current_symbol() else {
             return;
         };
@@ -178,18 +589,59 @@ impl<'tcx, 'a> SymbolBinder<'tcx, 'a> {
         }
     }
 
-    fn record_segments_dependency(&mut self, segments: &[String]) {
+    /// Resolve `segments` in namespace `ns`, returning whether it resolved -
+    /// an empty path counts as resolved (there's nothing to look up).
+    fn record_segments_dependency(&mut self, segments: &[String], ns: ResolutionNs) -> bool {
         if segments.is_empty() {
-            return;
+            return true;
         }
 
-        let target = self.core.lookup_segments_with_priority(
-            segments,
-            &[SymbolKind::Struct, SymbolKind::Enum, SymbolKind::Module],
-            None,
-        );
+        let target = self
+            .core
+            .lookup_segments_with_priority(segments, ns.priority(), None);
+        let resolved = target.is_some();
 
         self.add_symbol_relation(target);
+        resolved
+    }
+
+    /// Resolve every prefix of a dotted path (`pkg`, `pkg.mod`, `pkg.mod.Class`,
+    /// ...) against its own segment's span, instead of only the longest
+    /// match. Earlier prefixes typically resolve to modules, the full path to
+    /// whatever it denotes (struct/enum/module) - so each prefix gets a
+    /// dedicated `CrossRef`/dependency edge via `add_symbol_relation`, giving
+    /// go-to-definition a target for any component of the qualified name.
+    /// Returns whether the full path (its last, longest prefix) resolved -
+    /// an empty path counts as resolved (there's nothing to look up).
+    fn record_segment_prefixes(
+        &mut self,
+        segments: &[(String, (usize, usize))],
+        hir_id: HirId,
+    ) -> bool {
+        let mut prefix: Vec<String> = Vec::with_capacity(segments.len());
+        let mut resolved = segments.is_empty();
+        for (name, span) in segments {
+            prefix.push(name.clone());
+
+            let target = self.core.lookup_segments_with_priority(
+                &prefix,
+                &[
+                    SymbolKind::Struct,
+                    SymbolKind::Enum,
+                    SymbolKind::Function,
+                    SymbolKind::Module,
+                ],
+                None,
+            );
+            resolved = target.is_some();
+
+            if target.is_some() {
+                self.with_ref_context(*span, hir_id, "path-segment", |this| {
+                    this.add_symbol_relation(target)
+                });
+            }
+        }
+        resolved
     }
 
     fn record_decorator_dependency(&mut self, decorator: &str) {
@@ -210,42 +662,60 @@ impl<'tcx, 'a> SymbolBinder<'tcx, 'a> {
             return;
         }
 
-        self.record_segments_dependency(&segments);
+        // Decorators are invoked, so resolve them like any other callable.
+        self.record_segments_dependency(&segments, ResolutionNs::ValueNs);
     }
 
-    fn record_type_repr_dependencies(&mut self, text: &str) {
+    fn record_type_repr_dependencies(&mut self, text: &str, hir_id: HirId) {
         for segments in Self::segments_from_type_repr(text) {
-            self.record_segments_dependency(&segments);
+            if !self.record_segments_dependency(&segments, ResolutionNs::TypeNs) {
+                self.record_diagnostic(
+                    hir_id,
+                    BindingDiagnosticKind::UnresolvedType,
+                    segments.join("."),
+                );
+            }
         }
     }
 
-    fn add_type_expr_dependencies(&mut self, expr: &TypeExpr) {
+    /// Walk a parsed type expression, recording a dependency on every name it
+    /// references and a `BindingDiagnostic` for any that don't resolve in the
+    /// current scope chain. `hir_id` is the enclosing node (the function,
+    /// class, or assignment the expression came from) - `TypeExpr` itself
+    /// carries no node identity.
+    fn add_type_expr_dependencies(&mut self, expr: &TypeExpr, hir_id: HirId) {
         match expr {
             TypeExpr::Path { segments, generics } => {
-                if !segments.is_empty() {
-                    self.record_segments_dependency(segments);
+                if !segments.is_empty()
+                    && !self.record_segments_dependency(segments, ResolutionNs::TypeNs)
+                {
+                    self.record_diagnostic(
+                        hir_id,
+                        BindingDiagnosticKind::UnresolvedType,
+                        segments.join("."),
+                    );
                 }
                 for generic in generics {
-                    self.add_type_expr_dependencies(generic);
+                    self.add_type_expr_dependencies(generic, hir_id);
                 }
             }
-            TypeExpr::Reference { inner, .. } => self.add_type_expr_dependencies(inner),
+            TypeExpr::Reference { inner, .. } => self.add_type_expr_dependencies(inner, hir_id),
             TypeExpr::Tuple(items) => {
                 for item in items {
-                    self.add_type_expr_dependencies(item);
+                    self.add_type_expr_dependencies(item, hir_id);
                 }
             }
             TypeExpr::Callable { parameters, result } => {
                 for parameter in parameters {
-                    self.add_type_expr_dependencies(parameter);
+                    self.add_type_expr_dependencies(parameter, hir_id);
                 }
                 if let Some(result) = result.as_deref() {
-                    self.add_type_expr_dependencies(result);
+                    self.add_type_expr_dependencies(result, hir_id);
                 }
             }
-            TypeExpr::ImplTrait { bounds } => self.record_type_repr_dependencies(bounds),
+            TypeExpr::ImplTrait { bounds } => self.record_type_repr_dependencies(bounds, hir_id),
             TypeExpr::Opaque { repr, .. } | TypeExpr::Unknown(repr) => {
-                self.record_type_repr_dependencies(repr)
+                self.record_type_repr_dependencies(repr, hir_id)
             }
         }
     }
@@ -283,7 +753,14 @@ impl<'tcx, 'a> SymbolBinder<'tcx, 'a> {
         results
     }
 
-    fn build_attribute_path(&mut self, node: &HirNode<'tcx>, out: &mut Vec<String>) {
+    /// Descend `attribute`/`identifier` nodes, collecting each segment's name
+    /// alongside its own byte span so callers can resolve (and emit a
+    /// `CrossRef` for) every prefix of the path, not just the whole thing.
+    fn build_attribute_path(
+        &mut self,
+        node: &HirNode<'tcx>,
+        out: &mut Vec<(String, (usize, usize))>,
+    ) {
         if node.kind_id() == LangPython::attribute {
             if let Some(object_node) =
                 node.opt_child_by_field(self.unit(), LangPython::field_object)
@@ -294,12 +771,15 @@ impl<'tcx, 'a> SymbolBinder<'tcx, 'a> {
                 node.opt_child_by_field(self.unit(), LangPython::field_attribute)
             {
                 if let Some(ident) = attr_node.as_ident() {
-                    out.push(ident.name.clone());
+                    out.push((
+                        ident.name.clone(),
+                        (attr_node.start_byte(), attr_node.end_byte()),
+                    ));
                 }
             }
         } else if node.kind_id() == LangPython::identifier {
             if let Some(ident) = node.as_ident() {
-                out.push(ident.name.clone());
+                out.push((ident.name.clone(), (node.start_byte(), node.end_byte())));
             }
         } else {
             for child_id in node.children() {
@@ -309,10 +789,14 @@ impl<'tcx, 'a> SymbolBinder<'tcx, 'a> {
         }
     }
 
-    fn collect_identifier_paths(&mut self, node: &HirNode<'tcx>, results: &mut Vec<Vec<String>>) {
+    fn collect_identifier_paths(
+        &mut self,
+        node: &HirNode<'tcx>,
+        results: &mut Vec<Vec<(String, (usize, usize))>>,
+    ) {
         if node.kind_id() == LangPython::identifier {
             if let Some(ident) = node.as_ident() {
-                results.push(vec![ident.name.clone()]);
+                results.push(vec![(ident.name.clone(), (node.start_byte(), node.end_byte()))]);
             }
             return;
         }
@@ -336,19 +820,31 @@ impl<'tcx, 'a> SymbolBinder<'tcx, 'a> {
         let mut paths = Vec::new();
         self.collect_identifier_paths(node, &mut paths);
 
+        let hir_id = node.hir_id();
         let mut seen = HashSet::new();
         for path in paths {
             if path.is_empty() {
                 continue;
             }
-            let key = path.join("::");
-            if seen.insert(key) {
-                self.record_segments_dependency(&path);
+            let key = path
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>()
+                .join("::");
+            if seen.insert(key) && !self.record_segment_prefixes(&path, hir_id) {
+                let text = path
+                    .iter()
+                    .map(|(name, _)| name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(".");
+                self.record_diagnostic(hir_id, BindingDiagnosticKind::UnresolvedType, text);
             }
         }
     }
 
-    fn record_import_path(&mut self, path: &str) {
+    /// Returns whether `path` resolved - an empty path counts as resolved
+    /// (there's nothing to look up).
+    fn record_import_path(&mut self, path: &str) -> bool {
         let normalized = path.replace("::", ".");
         let segments: Vec<String> = normalized
             .split('.')
@@ -356,7 +852,7 @@ impl<'tcx, 'a> SymbolBinder<'tcx, 'a> {
             .map(|segment| segment.trim().to_string())
             .collect();
         if segments.is_empty() {
-            return;
+            return true;
         }
 
         let target = self.core.lookup_segments_with_priority(
@@ -364,17 +860,84 @@ impl<'tcx, 'a> SymbolBinder<'tcx, 'a> {
             &[SymbolKind::Struct, SymbolKind::Enum, SymbolKind::Module],
             None,
         );
+        let resolved = target.is_some();
 
         self.add_symbol_relation(target);
+        resolved
+    }
+
+    /// Side-effect-free variant of `record_import_path` for a `resolve_at`
+    /// query - looks up the same segments without recording a `CrossRef`.
+    fn resolve_import_symbol(&mut self, path: &str) -> Option<&'tcx Symbol> {
+        let normalized = path.replace("::", ".");
+        let segments: Vec<String> = normalized
+            .split('.')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| segment.trim().to_string())
+            .collect();
+        if segments.is_empty() {
+            return None;
+        }
+
+        self.core.lookup_segments_with_priority(
+            &segments,
+            &[SymbolKind::Struct, SymbolKind::Enum, SymbolKind::Module],
+            None,
+        )
+    }
+
+    /// Side-effect-free variant of `process_call_descriptor` for a
+    /// `resolve_at` query - resolves the same target, through the same
+    /// fallback chain, without recording a call binding or diagnostic.
+    fn resolve_call_symbol(
+        &mut self,
+        descriptor: &llmcc_descriptor::CallDescriptor,
+    ) -> Option<&'tcx Symbol> {
+        match &descriptor.target {
+            llmcc_descriptor::CallTarget::Symbol(symbol) => {
+                let mut segments = symbol.qualifiers.clone();
+                segments.push(symbol.name.clone());
+                self.lookup_segments_fallback(&segments, ResolutionNs::ValueNs)
+                    .or_else(|| {
+                        self.lookup_segments_fallback(
+                            std::slice::from_ref(&symbol.name),
+                            ResolutionNs::ValueNs,
+                        )
+                    })
+            }
+            llmcc_descriptor::CallTarget::Chain(chain) => self
+                .resolve_method_from_chain(chain)
+                .or_else(|| {
+                    let segment = chain.segments.last()?;
+                    self.lookup_segments_fallback(
+                        std::slice::from_ref(&segment.name),
+                        ResolutionNs::ValueNs,
+                    )
+                }),
+            llmcc_descriptor::CallTarget::Dynamic { .. } => None,
+        }
     }
 
-    fn process_call_descriptor(&mut self, descriptor: &llmcc_descriptor::CallDescriptor) {
+    fn process_call_descriptor(
+        &mut self,
+        descriptor: &llmcc_descriptor::CallDescriptor,
+        hir_id: HirId,
+    ) {
         match &descriptor.target {
             llmcc_descriptor::CallTarget::Symbol(symbol) => {
                 let mut segments = symbol.qualifiers.clone();
                 segments.push(symbol.name.clone());
-                if !self.handle_symbol_segments(&segments) {
-                    self.handle_symbol_segments(std::slice::from_ref(&symbol.name));
+                if !self.handle_symbol_segments(&segments, ResolutionNs::ValueNs)
+                    && !self.handle_symbol_segments(
+                        std::slice::from_ref(&symbol.name),
+                        ResolutionNs::ValueNs,
+                    )
+                {
+                    self.record_diagnostic(
+                        hir_id,
+                        BindingDiagnosticKind::UnresolvedCall,
+                        segments.join("."),
+                    );
                 }
             }
             llmcc_descriptor::CallTarget::Chain(chain) => {
@@ -382,39 +945,51 @@ impl<'tcx, 'a> SymbolBinder<'tcx, 'a> {
                     self.add_symbol_relation(Some(target));
                     self.record_call_binding(target);
                 } else if let Some(segment) = chain.segments.last() {
-                    self.handle_symbol_segments(std::slice::from_ref(&segment.name));
+                    if !self.handle_symbol_segments(
+                        std::slice::from_ref(&segment.name),
+                        ResolutionNs::ValueNs,
+                    ) {
+                        self.record_diagnostic(
+                            hir_id,
+                            BindingDiagnosticKind::UnresolvedCall,
+                            segment.name.clone(),
+                        );
+                    }
                 }
             }
             llmcc_descriptor::CallTarget::Dynamic { .. } => {}
         }
     }
 
-    fn handle_symbol_segments(&mut self, segments: &[String]) -> bool {
+    /// Shared priority-then-plain segment lookup used by both
+    /// `handle_symbol_segments` (which additionally records the relation/call
+    /// binding) and `resolve_call_symbol` (which must try the exact same
+    /// fallback chain without those side effects, so `resolve_at` can't drift
+    /// out of sync with `bind_symbols`).
+    fn lookup_segments_fallback(
+        &mut self,
+        segments: &[String],
+        ns: ResolutionNs,
+    ) -> Option<&'tcx Symbol> {
         if segments.is_empty() {
-            return false;
+            return None;
         }
 
-        if let Some(target) = self.core.lookup_segments_with_priority(
-            segments,
-            &[SymbolKind::Function, SymbolKind::Struct],
-            None,
-        ) {
-            self.add_symbol_relation(Some(target));
-            if target.kind() == SymbolKind::Function {
-                self.record_call_binding(target);
-            }
-            return true;
-        }
+        self.core
+            .lookup_segments_with_priority(segments, ns.priority(), None)
+            .or_else(|| self.core.lookup_segments(segments, None, None))
+    }
 
-        if let Some(target) = self.core.lookup_segments(segments, None, None) {
-            self.add_symbol_relation(Some(target));
-            if target.kind() == SymbolKind::Function {
-                self.record_call_binding(target);
-            }
-            return true;
-        }
+    fn handle_symbol_segments(&mut self, segments: &[String], ns: ResolutionNs) -> bool {
+        let Some(target) = self.lookup_segments_fallback(segments, ns) else {
+            return false;
+        };
 
-        false
+        self.add_symbol_relation(Some(target));
+        if target.kind() == SymbolKind::Function {
+            self.record_call_binding(target);
+        }
+        true
     }
 
     fn record_call_binding(&mut self, target: &Symbol) {
@@ -446,18 +1021,42 @@ impl<'tcx, 'a> SymbolBinder<'tcx, 'a> {
                 .map(|symbol| symbol.fqn_name.read().clone());
 
             if let Some(class_fqn) = class_fqn {
-                let method_fqn = format!("{}::{}", class_fqn, segment.name);
-                let key = self.interner().intern(&method_fqn);
-                return self
-                    .core
-                    .lookup_symbol_suffix(&[key], Some(SymbolKind::Function), None)
-                    .or_else(|| self.core.lookup_symbol_suffix(&[key], None, None));
+                if let Some(target) = self.resolve_method_on_class(&class_fqn, &segment.name) {
+                    return Some(target);
+                }
+            }
+        }
+
+        // Non-`self` root: if it's a plain identifier the local-type environment
+        // maps to a class (from a typed parameter or a `x = Class(...)`
+        // assignment), resolve the method against that class the same way.
+        if let CallChainRoot::Expr(expr) = &chain.root {
+            let trimmed = expr.trim();
+            if let Some(class_symbol) = self.lookup_local_type(trimmed) {
+                let class_fqn = class_symbol.fqn_name.read().clone();
+                if let Some(target) = self.resolve_method_on_class(&class_fqn, &segment.name) {
+                    return Some(target);
+                }
             }
         }
 
         None
     }
 
+    /// Resolve `class_fqn::method_name` to a function symbol, falling back to
+    /// any symbol with that FQN if no `Function`-kinded match exists.
+    fn resolve_method_on_class(
+        &mut self,
+        class_fqn: &str,
+        method_name: &str,
+    ) -> Option<&'tcx Symbol> {
+        let method_fqn = format!("{}::{}", class_fqn, method_name);
+        let key = self.interner().intern(&method_fqn);
+        self.core
+            .lookup_symbol_suffix(&[key], Some(SymbolKind::Function), None)
+            .or_else(|| self.core.lookup_symbol_suffix(&[key], None, None))
+    }
+
     fn add_base_class_dependencies(&mut self, node: &HirNode<'tcx>, class_symbol: &Symbol) {
         for child_id in node.children() {
             let child = self.unit().hir_node(*child_id);
@@ -543,14 +1142,41 @@ impl<'tcx, 'a> SymbolBinder<'tcx, 'a> {
     }
 }
 
-impl<'tcx> AstVisitorPython<'tcx> for SymbolBinder<'tcx, '_> {
+impl<'tcx, B> AstVisitorPython<'tcx, B> for SymbolBinder<'tcx, '_, B> {
     type ScopedSymbol = &'tcx Symbol;
 
     fn unit(&self) -> CompileUnit<'tcx> {
         self.core.unit()
     }
 
-    fn visit_children_scope(&mut self, node: &HirNode<'tcx>, symbol: Option<Self::ScopedSymbol>) {
+    /// Walk `node`'s children in order, stopping as soon as one returns
+    /// `Break` instead of visiting the rest of the subtree. When a
+    /// `resolve_at` query is in progress (`target_pos` is set), descends
+    /// only into the single child whose span contains the cursor instead of
+    /// the whole subtree.
+    fn visit_children(&mut self, node: &HirNode<'tcx>) -> ControlFlow<B, ()> {
+        if let Some(pos) = self.target_pos {
+            for child_id in node.children() {
+                let child = self.unit().hir_node(*child_id);
+                if child.start_byte() <= pos && pos <= child.end_byte() {
+                    return self.visit_node(&child);
+                }
+            }
+            return ControlFlow::Continue(());
+        }
+
+        for child_id in node.children() {
+            let child = self.unit().hir_node(*child_id);
+            self.visit_node(&child)?;
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_children_scope(
+        &mut self,
+        node: &HirNode<'tcx>,
+        symbol: Option<Self::ScopedSymbol>,
+    ) -> ControlFlow<B, ()> {
         let depth = self.scopes().depth();
         if let Some(symbol) = symbol {
             if let Some(parent) = self.current_symbol() {
@@ -561,34 +1187,31 @@ impl<'tcx> AstVisitorPython<'tcx> for SymbolBinder<'tcx, '_> {
         let scope = self.unit().opt_get_scope(node.hir_id());
         if let Some(scope) = scope {
             self.scopes_mut().push_with_symbol(scope, symbol);
-            self.visit_children(node);
+            let local_depth = self.push_local_scope();
+            let result = self.visit_children(node);
+            self.pop_local_scope(local_depth);
             self.scopes_mut().pop_until(depth);
+            result
         } else {
-            self.visit_children(node);
+            self.visit_children(node)
         }
     }
 
-    fn visit_source_file(&mut self, node: HirNode<'tcx>) {
+    fn visit_source_file(&mut self, node: HirNode<'tcx>) -> ControlFlow<B, ()> {
         self.module_imports.clear();
         let module_symbol = self.ensure_module_symbol(&node);
-        self.visit_children_scope(&node, module_symbol);
+        self.visit_children_scope(&node, module_symbol)
     }
 
-    fn visit_function_definition(&mut self, node: HirNode<'tcx>) {
+    fn visit_function_definition(&mut self, node: HirNode<'tcx>) -> ControlFlow<B, ()> {
         let name_node = match node.opt_child_by_field(self.unit(), LangPython::field_name) {
             Some(n) => n,
-            None => {
-                self.visit_children(&node);
-                return;
-            }
+            None => return self.visit_children(&node),
         };
 
         let ident = match name_node.as_ident() {
             Some(id) => id,
-            None => {
-                self.visit_children(&node);
-                return;
-            }
+            None => return self.visit_children(&node),
         };
 
         let key = self.interner().intern(&ident.name);
@@ -607,8 +1230,11 @@ impl<'tcx> AstVisitorPython<'tcx> for SymbolBinder<'tcx, '_> {
 
             let depth = self.scopes().depth();
             self.scopes_mut().push_with_symbol(scope, symbol);
+            let local_depth = self.push_local_scope();
 
             if let Some(current_symbol) = self.current_symbol() {
+                self.record_def(current_symbol, &node);
+
                 // If parent is a class, class depends on method
                 if let Some(parent) = parent_symbol {
                     if parent.kind() == SymbolKind::Struct {
@@ -619,12 +1245,18 @@ impl<'tcx> AstVisitorPython<'tcx> for SymbolBinder<'tcx, '_> {
 
                 if let Some(descriptor) = self.collection().functions.find(node.hir_id()) {
                     if let Some(return_type) = descriptor.return_type.as_ref() {
-                        self.add_type_expr_dependencies(return_type);
+                        self.add_type_expr_dependencies(return_type, node.hir_id());
                     }
 
                     for parameter in &descriptor.parameters {
                         if let Some(type_expr) = parameter.type_hint.as_ref() {
-                            self.add_type_expr_dependencies(type_expr);
+                            self.add_type_expr_dependencies(type_expr, node.hir_id());
+                            if let Some(param_name) = parameter.name.as_ref() {
+                                if let Some(class_symbol) = self.resolve_type_expr_class(type_expr)
+                                {
+                                    self.bind_local_type(param_name, class_symbol);
+                                }
+                            }
                         }
                     }
 
@@ -642,28 +1274,24 @@ impl<'tcx> AstVisitorPython<'tcx> for SymbolBinder<'tcx, '_> {
                 }
             }
 
-            self.visit_children(&node);
+            let result = self.visit_children(&node);
+            self.pop_local_scope(local_depth);
             self.scopes_mut().pop_until(depth);
+            result
         } else {
-            self.visit_children(&node);
+            self.visit_children(&node)
         }
     }
 
-    fn visit_class_definition(&mut self, node: HirNode<'tcx>) {
+    fn visit_class_definition(&mut self, node: HirNode<'tcx>) -> ControlFlow<B, ()> {
         let name_node = match node.opt_child_by_field(self.unit(), LangPython::field_name) {
             Some(n) => n,
-            None => {
-                self.visit_children(&node);
-                return;
-            }
+            None => return self.visit_children(&node),
         };
 
         let ident = match name_node.as_ident() {
             Some(id) => id,
-            None => {
-                self.visit_children(&node);
-                return;
-            }
+            None => return self.visit_children(&node),
         };
 
         let key = self.interner().intern(&ident.name);
@@ -679,11 +1307,14 @@ impl<'tcx> AstVisitorPython<'tcx> for SymbolBinder<'tcx, '_> {
 
             let depth = self.scopes().depth();
             self.scopes_mut().push_with_symbol(scope, symbol);
+            let local_depth = self.push_local_scope();
 
             if let Some(current_symbol) = self.current_symbol() {
+                self.record_def(current_symbol, &node);
+
                 if let Some(descriptor) = self.collection().classes.find(node.hir_id()) {
                     for base in &descriptor.base_types {
-                        self.add_type_expr_dependencies(base);
+                        self.add_type_expr_dependencies(base, node.hir_id());
                     }
                     for decorator in &descriptor.decorators {
                         self.record_decorator_dependency(decorator);
@@ -696,33 +1327,62 @@ impl<'tcx> AstVisitorPython<'tcx> for SymbolBinder<'tcx, '_> {
                 }
             }
 
-            self.visit_children(&node);
+            let result = self.visit_children(&node);
+            self.pop_local_scope(local_depth);
             self.scopes_mut().pop_until(depth);
+            result
         } else {
-            self.visit_children(&node);
+            self.visit_children(&node)
         }
     }
 
-    fn visit_decorated_definition(&mut self, node: HirNode<'tcx>) {
-        self.visit_children(&node);
+    fn visit_decorated_definition(&mut self, node: HirNode<'tcx>) -> ControlFlow<B, ()> {
+        self.visit_children(&node)
     }
 
-    fn visit_block(&mut self, node: HirNode<'tcx>) {
-        self.visit_children_scope(&node, None);
+    fn visit_block(&mut self, node: HirNode<'tcx>) -> ControlFlow<B, ()> {
+        self.visit_children_scope(&node, None)
     }
 
-    fn visit_call(&mut self, node: HirNode<'tcx>) {
+    fn visit_call(&mut self, node: HirNode<'tcx>) -> ControlFlow<B, ()> {
         if let Some(descriptor) = self.collection().calls.find(node.hir_id()) {
-            self.process_call_descriptor(descriptor);
+            if self.target_pos.is_some() {
+                self.position_result = self
+                    .resolve_call_symbol(descriptor)
+                    .map(PositionBinding::from_symbol);
+            } else {
+                let hir_id = node.hir_id();
+                self.with_ref_context((node.start_byte(), node.end_byte()), hir_id, "call", |this| {
+                    this.process_call_descriptor(descriptor, hir_id)
+                });
+                if let Some(call) = self.calls.last() {
+                    self.check_predicate(&BindEvent::Call {
+                        caller: &call.caller,
+                        target: &call.target,
+                    })?;
+                }
+            }
         }
-        self.visit_children(&node);
+        self.visit_children(&node)
     }
 
-    fn visit_assignment(&mut self, node: HirNode<'tcx>) {
+    fn visit_assignment(&mut self, node: HirNode<'tcx>) -> ControlFlow<B, ()> {
         let mut handled = false;
         if let Some(descriptor) = self.collection().variables.find(node.hir_id()) {
             if let Some(type_expr) = descriptor.type_annotation.as_ref() {
-                self.add_type_expr_dependencies(type_expr);
+                if self.target_pos.is_some() {
+                    self.position_result = self
+                        .resolve_type_expr_class(type_expr)
+                        .map(PositionBinding::from_symbol);
+                } else {
+                    let hir_id = node.hir_id();
+                    self.with_ref_context(
+                        (node.start_byte(), node.end_byte()),
+                        hir_id,
+                        "type-annotation",
+                        |this| this.add_type_expr_dependencies(type_expr, hir_id),
+                    );
+                }
                 handled = true;
             }
         }
@@ -740,25 +1400,69 @@ impl<'tcx> AstVisitorPython<'tcx> for SymbolBinder<'tcx, '_> {
             }
         }
 
-        self.visit_children(&node);
+        self.bind_local_type_from_constructor(&node);
+
+        self.visit_children(&node)
     }
 
-    fn visit_import_statement(&mut self, node: HirNode<'tcx>) {
+    fn visit_import_statement(&mut self, node: HirNode<'tcx>) -> ControlFlow<B, ()> {
         if let Some(descriptor) = self.collection().imports.find(node.hir_id()) {
-            self.record_import_path(&descriptor.source);
+            if self.target_pos.is_some() {
+                self.position_result = self
+                    .resolve_import_symbol(&descriptor.source)
+                    .map(PositionBinding::from_symbol);
+            } else {
+                let resolved = self.with_ref_context(
+                    (node.start_byte(), node.end_byte()),
+                    node.hir_id(),
+                    "import",
+                    |this| this.record_import_path(&descriptor.source),
+                );
+                if !resolved {
+                    self.record_diagnostic(
+                        node.hir_id(),
+                        BindingDiagnosticKind::UnresolvedImport,
+                        descriptor.source.clone(),
+                    );
+                }
+                self.check_predicate(&BindEvent::Import {
+                    path: &descriptor.source,
+                })?;
+            }
         }
-        self.visit_children(&node);
+        self.visit_children(&node)
     }
 
-    fn visit_import_from(&mut self, node: HirNode<'tcx>) {
+    fn visit_import_from(&mut self, node: HirNode<'tcx>) -> ControlFlow<B, ()> {
         if let Some(descriptor) = self.collection().imports.find(node.hir_id()) {
-            self.record_import_path(&descriptor.source);
+            if self.target_pos.is_some() {
+                self.position_result = self
+                    .resolve_import_symbol(&descriptor.source)
+                    .map(PositionBinding::from_symbol);
+            } else {
+                let resolved = self.with_ref_context(
+                    (node.start_byte(), node.end_byte()),
+                    node.hir_id(),
+                    "import",
+                    |this| this.record_import_path(&descriptor.source),
+                );
+                if !resolved {
+                    self.record_diagnostic(
+                        node.hir_id(),
+                        BindingDiagnosticKind::UnresolvedImport,
+                        descriptor.source.clone(),
+                    );
+                }
+                self.check_predicate(&BindEvent::Import {
+                    path: &descriptor.source,
+                })?;
+            }
         }
-        self.visit_children(&node);
+        self.visit_children(&node)
     }
 
-    fn visit_unknown(&mut self, node: HirNode<'tcx>) {
-        self.visit_children(&node);
+    fn visit_unknown(&mut self, node: HirNode<'tcx>) -> ControlFlow<B, ()> {
+        self.visit_children(&node)
     }
 }
 
@@ -767,15 +1471,67 @@ pub fn bind_symbols<'tcx>(
     globals: &'tcx Scope<'tcx>,
     collection: &CollectedSymbols,
 ) -> BindingResult {
-    let mut binder = SymbolBinder::new(unit, globals, &collection.result);
+    let mut binder: SymbolBinder<'_, '_, std::convert::Infallible> =
+        SymbolBinder::new(unit, globals, &collection.result, None);
 
     if let Some(file_start_id) = unit.file_start_hir_id() {
         if let Some(root) = unit.opt_hir_node(file_start_id) {
-            binder.visit_children(&root);
+            let _ = binder.visit_children(&root);
         }
     }
 
     BindingResult {
         calls: binder.calls,
+        cross_refs: CrossRefIndex {
+            defs: binder.defs,
+            refs: binder.refs,
+        },
+        diagnostics: binder.diagnostics,
+        references: binder.references,
     }
 }
+
+/// Run a bounded binder walk over `unit`, stopping at the first node for
+/// which `pred` returns `Some` - e.g. the first call to a given symbol, or
+/// the first import of a given path - instead of binding the whole file.
+pub fn find_binding_where<'tcx, B>(
+    unit: CompileUnit<'tcx>,
+    globals: &'tcx Scope<'tcx>,
+    collection: &CollectedSymbols,
+    pred: impl FnMut(&BindEvent<'_>) -> Option<B> + 'tcx,
+) -> Option<B> {
+    let mut binder = SymbolBinder::new(unit, globals, &collection.result, Some(Box::new(pred)));
+
+    let file_start_id = unit.file_start_hir_id()?;
+    let root = unit.opt_hir_node(file_start_id)?;
+
+    match binder.visit_children(&root) {
+        ControlFlow::Break(value) => Some(value),
+        ControlFlow::Continue(()) => None,
+    }
+}
+
+/// Resolve a go-to-definition-style cursor query: the symbol whose HIR node
+/// (a call target, an annotated assignment's type, or an imported path)
+/// most tightly encloses byte offset `pos`. Sets `target_pos` so
+/// `visit_children` descends only into the child whose span contains `pos`,
+/// instead of running the full binder over the file.
+pub fn resolve_at<'tcx>(
+    unit: CompileUnit<'tcx>,
+    globals: &'tcx Scope<'tcx>,
+    collection: &CollectedSymbols,
+    pos: usize,
+) -> Option<PositionBinding> {
+    let file_start_id = unit.file_start_hir_id()?;
+    let root = unit.opt_hir_node(file_start_id)?;
+    if !(root.start_byte() <= pos && pos <= root.end_byte()) {
+        return None;
+    }
+
+    let mut binder: SymbolBinder<'_, '_, std::convert::Infallible> =
+        SymbolBinder::new(unit, globals, &collection.result, None);
+    binder.target_pos = Some(pos);
+
+    let _ = binder.visit_children(&root);
+    binder.position_result
+}
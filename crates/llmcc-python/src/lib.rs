@@ -3,7 +3,7 @@ mod collect;
 pub mod describe;
 pub mod token;
 
-pub use crate::bind::{BindingResult, bind_symbols};
+pub use crate::bind::{BindEvent, BindingResult, bind_symbols, find_binding_where, resolve_at};
 pub use crate::collect::collect_symbols;
 pub use llmcc_core::{
     CompileCtxt, ProjectGraph, ProjectQuery, build_llmcc_graph, build_llmcc_ir, print_llmcc_graph,
@@ -1,12 +1,15 @@
 use llmcc_core::context::CompileCtxt;
-use llmcc_python::{bind_symbols, LangPython};
+use llmcc_python::{
+    bind_symbols, collect_symbols, find_binding_where, resolve_at, BindEvent, LangPython,
+};
 
 fn bind_from_source(source: &str) -> llmcc_python::BindingResult {
     let sources = vec![source.as_bytes().to_vec()];
     let cc = CompileCtxt::from_sources::<LangPython>(&sources);
     let unit = cc.compile_unit(0);
     let globals = cc.create_globals();
-    bind_symbols(unit, globals)
+    let collection = collect_symbols(unit);
+    bind_symbols(unit, globals, &collection)
 }
 
 #[test]
@@ -126,3 +129,165 @@ if __name__ == "__main__":
 "#;
     let _result = bind_from_source(source);
 }
+
+#[test]
+fn test_bind_records_a_cross_ref_per_dotted_path_prefix() {
+    let source = "import os\nos.path.exists('.')\n";
+    let result = bind_from_source(source);
+
+    assert!(
+        result.cross_refs.refs.len() >= 2,
+        "each prefix of `os.path` should get its own CrossRef, got {:?}",
+        result.cross_refs.refs
+    );
+}
+
+#[test]
+fn test_bind_resolves_method_call_via_local_type_inference() {
+    let source = r#"
+class MyClass:
+    def method(self):
+        pass
+
+obj = MyClass()
+obj.method()
+"#;
+    let result = bind_from_source(source);
+
+    assert!(
+        !result.has_errors(),
+        "obj.method() should resolve now that `obj` is bound to MyClass, diagnostics: {:?}",
+        result.diagnostics
+    );
+}
+
+#[test]
+fn test_bind_resolves_same_name_in_type_and_value_position() {
+    let source = r#"
+class Widget:
+    pass
+
+w: Widget = Widget()
+"#;
+    let result = bind_from_source(source);
+
+    assert!(
+        !result.has_errors(),
+        "both the `Widget` annotation and the `Widget()` call should resolve, diagnostics: {:?}",
+        result.diagnostics
+    );
+}
+
+#[test]
+fn test_references_to_finds_every_occurrence_of_a_symbol() {
+    let source = "def helper():\n    pass\n\nhelper()\nhelper()\n";
+    let result = bind_from_source(source);
+
+    let helper_symbol = result
+        .references
+        .first()
+        .map(|reference| reference.target)
+        .expect("both calls to helper() should have recorded a Reference");
+
+    let occurrences = result.references_to(helper_symbol);
+    assert_eq!(
+        occurrences.len(),
+        2,
+        "both calls to helper() should be recorded as references to the same symbol"
+    );
+}
+
+#[test]
+fn test_resolve_at_finds_go_to_definition_target() {
+    let source = "def helper():\n    pass\n\nhelper()\n";
+    let call_pos = source.rfind("helper").expect("call site should be present");
+
+    let sources = vec![source.as_bytes().to_vec()];
+    let cc = CompileCtxt::from_sources::<LangPython>(&sources);
+    let unit = cc.compile_unit(0);
+    let globals = cc.create_globals();
+    let collection = collect_symbols(unit);
+
+    let binding =
+        resolve_at(unit, globals, &collection, call_pos).expect("cursor should resolve to helper");
+    assert!(binding.fqn.ends_with("helper"));
+}
+
+#[test]
+fn test_bind_reports_diagnostic_for_unresolved_call() {
+    let source = "undefined_func()\n";
+    let result = bind_from_source(source);
+
+    assert!(
+        result.has_errors(),
+        "calling an undefined function should leave a diagnostic behind"
+    );
+    assert!(result
+        .diagnostics
+        .iter()
+        .any(|diagnostic| diagnostic.text.contains("undefined_func")));
+}
+
+#[test]
+fn test_find_binding_where_stops_at_first_matching_call() {
+    let source = "def inner():\n    pass\n\ndef outer():\n    inner()\n";
+    let sources = vec![source.as_bytes().to_vec()];
+    let cc = CompileCtxt::from_sources::<LangPython>(&sources);
+    let unit = cc.compile_unit(0);
+    let globals = cc.create_globals();
+    let collection = collect_symbols(unit);
+
+    let found = find_binding_where(unit, globals, &collection, |event| match event {
+        BindEvent::Call { target, .. } if target.ends_with("inner") => Some(target.to_string()),
+        _ => None,
+    });
+
+    assert_eq!(found.as_deref(), Some("inner"));
+}
+
+#[test]
+fn test_call_graph_navigates_callers_callees_and_reachability() {
+    let source = "def inner():\n    pass\n\ndef outer():\n    inner()\n\ndef top():\n    outer()\n";
+    let result = bind_from_source(source);
+    let graph = result.call_graph();
+
+    assert!(
+        graph
+            .callees_of("top")
+            .iter()
+            .any(|callee| callee == "outer"),
+        "top should call outer"
+    );
+    assert!(
+        graph
+            .callers_of("inner")
+            .iter()
+            .any(|caller| caller == "outer"),
+        "outer should be a caller of inner"
+    );
+    assert!(
+        graph
+            .reachable_from("top")
+            .iter()
+            .any(|fqn| fqn == "inner"),
+        "inner should be transitively reachable from top"
+    );
+}
+
+#[test]
+fn test_cross_ref_index_round_trips_defs_and_refs() {
+    let source = "def helper():\n    pass\n\ndef caller():\n    helper()\n";
+    let result = bind_from_source(source);
+
+    assert!(
+        !result.cross_refs.defs.is_empty(),
+        "expected at least one recorded definition"
+    );
+    assert!(
+        !result.cross_refs.refs.is_empty(),
+        "expected the call to helper() to produce a CrossRef"
+    );
+
+    let json = result.cross_refs.to_json().expect("cross_refs should serialize");
+    assert!(json.contains("helper"), "serialized JSON should mention `helper`");
+}
@@ -138,6 +138,7 @@ impl<'a> CollectorScopes<'a> {
             symbol.set_kind(kind);
             symbol.set_unit_index(self.unit_index());
             symbol.add_defining(node.id());
+            symbol.set_span(node.start_byte(), node.end_byte());
             if let Some(parent) = self.top() {
                 symbol.set_parent_scope(parent.id());
             }
@@ -8,8 +8,9 @@ use llmcc_resolver::{BinderScopes, ResolverOption};
 
 use strum::IntoEnumIterator;
 
-use crate::infer::infer_type;
-use crate::pattern::bind_pattern_types;
+use crate::exhaustive::check_match_expression;
+use crate::infer::{infer_iterable_element_type, infer_type, resolve_path_symbol};
+use crate::pattern::{bind_pattern_types, check_struct_expression_fields, check_struct_pattern_fields};
 use crate::token::AstVisitorRust;
 use crate::token::LangRust;
 use crate::util::{parse_crate_name, parse_file_name, parse_module_name};
@@ -221,6 +222,48 @@ impl<'tcx> AstVisitorRust<'tcx, BinderScopes<'tcx>> for BinderVisitor<'tcx> {
         scopes.pop_scope();
     }
 
+    // AST: |param1, param2| { body } - closure/anonymous function
+    fn visit_closure_expression(
+        &mut self,
+        unit: &CompileUnit<'tcx>,
+        node: &HirNode<'tcx>,
+        scopes: &mut BinderScopes<'tcx>,
+        namespace: &'tcx Scope<'tcx>,
+        parent: Option<&Symbol>,
+    ) {
+        let sn = node.as_scope().unwrap();
+        scopes.push_scope(sn.scope().id());
+        self.visit_children(unit, node, scopes, namespace, parent);
+        scopes.pop_scope();
+    }
+
+    // AST: for pattern in iterable { body }
+    // Binds the loop pattern against the iterable's element type before
+    // descending into the body, so references to the loop variable(s)
+    // resolve to their inferred type.
+    #[tracing::instrument(skip_all)]
+    fn visit_for_expression(
+        &mut self,
+        unit: &CompileUnit<'tcx>,
+        node: &HirNode<'tcx>,
+        scopes: &mut BinderScopes<'tcx>,
+        namespace: &'tcx Scope<'tcx>,
+        parent: Option<&Symbol>,
+    ) {
+        let sn = node.as_scope().unwrap();
+        scopes.push_scope(sn.scope().id());
+
+        if let Some(pattern) = node.child_by_field(unit, LangRust::field_pattern)
+            && let Some(iterable) = node.child_by_field(unit, LangRust::field_value)
+            && let Some(element_type) = infer_iterable_element_type(unit, scopes, &iterable)
+        {
+            bind_pattern_types(unit, scopes, &pattern, element_type);
+        }
+
+        self.visit_children(unit, node, scopes, namespace, parent);
+        scopes.pop_scope();
+    }
+
     // AST: mod name { ... items ... }
     #[tracing::instrument(skip_all)]
     fn visit_mod_item(
@@ -596,9 +639,42 @@ impl<'tcx> AstVisitorRust<'tcx, BinderScopes<'tcx>> for BinderVisitor<'tcx> {
                 scopes.lookup_symbol(&tuple_ident.name, vec![SymKind::CompositeType])
             && tuple_symbol.nested_types().is_none()
         {
-            for type_ident in node.collect_idents(unit) {
-                if let Some(type_sym) = type_ident.opt_symbol() {
-                    tuple_symbol.add_nested_type(type_sym.id());
+            // Resolve each direct element by type, not by flattening every
+            // identifier in the subtree - a nested `(Type1, Type2)` element
+            // must stay a single CompositeType component, not two flattened
+            // elements, so nested tuple/struct patterns can recurse correctly.
+            for child in node.children(unit) {
+                if child.is_trivia() {
+                    continue;
+                }
+                if let Some(elem_sym) = infer_type(unit, scopes, &child) {
+                    tuple_symbol.add_nested_type(elem_sym.id());
+                }
+            }
+        }
+    }
+
+    // AST: (Type1, Type2) - body of a tuple struct (`struct Point(i32, i32);`)
+    // or a tuple enum variant (`Succ(i32)`)
+    fn visit_ordered_field_declaration_list(
+        &mut self,
+        unit: &CompileUnit<'tcx>,
+        node: &HirNode<'tcx>,
+        scopes: &mut BinderScopes<'tcx>,
+        namespace: &'tcx Scope<'tcx>,
+        parent: Option<&Symbol>,
+    ) {
+        self.visit_children(unit, node, scopes, namespace, parent);
+
+        if let Some(owner_sym) = namespace.opt_symbol()
+            && owner_sym.nested_types().is_none()
+        {
+            for child in node.children(unit) {
+                if child.is_trivia() {
+                    continue;
+                }
+                if let Some(field_type) = infer_type(unit, scopes, &child) {
+                    owner_sym.add_nested_type(field_type.id());
                 }
             }
         }
@@ -795,7 +871,8 @@ impl<'tcx> AstVisitorRust<'tcx, BinderScopes<'tcx>> for BinderVisitor<'tcx> {
         }
     }
 
-    // AST: Pattern { field1, field2 } or TupleVariant(a, b, c)
+    // AST: TupleVariant(a, b, c) or TupleStruct(x, y) - also covers qualified
+    // enum-variant payload patterns like `Nat::Succ(x)`.
     fn visit_tuple_struct_pattern(
         &mut self,
         unit: &CompileUnit<'tcx>,
@@ -808,24 +885,53 @@ impl<'tcx> AstVisitorRust<'tcx, BinderScopes<'tcx>> for BinderVisitor<'tcx> {
 
         let type_node = node.child_by_field(unit, LangRust::field_type);
         if let Some(type_node) = type_node
-            && let Some(type_ident) = type_node.find_ident(unit)
-            // type_sym is the struct type
-            && let Some(type_sym) = type_ident.opt_symbol()
+            // type_sym is the tuple struct or enum variant being matched
+            && let Some(type_sym) = resolve_path_symbol(unit, &type_node)
+            && let Some(nested_types) = type_sym.nested_types()
         {
-            if type_sym.nested_types().is_some() {
-                for (i, child) in node.collect_idents(unit).into_iter().enumerate() {
-                    if let Some(child_sym) = child.opt_symbol()
-                        && let Some(nested_types) = type_sym.nested_types()
-                        && i >= 2
-                        && i < nested_types.len()
-                    {
-                        child_sym.set_type_of(nested_types[i]);
-                    }
+            let mut element_index = 0;
+            for child in node.children(unit) {
+                // Skip the type/path field and punctuation - only the
+                // positional payload patterns are bound to nested_types.
+                if child.field_id() == LangRust::field_type || child.is_trivia() {
+                    continue;
                 }
+
+                if let Some(element_type) = nested_types
+                    .get(element_index)
+                    .and_then(|id| unit.opt_get_symbol(*id))
+                {
+                    bind_pattern_types(unit, scopes, &child, element_type);
+                }
+
+                element_index += 1;
             }
         }
     }
 
+    // AST: StructName { field1, field2 } - also covers qualified struct-variant
+    // payload patterns like `Nat::Node { value, next }`.
+    fn visit_struct_pattern(
+        &mut self,
+        unit: &CompileUnit<'tcx>,
+        node: &HirNode<'tcx>,
+        scopes: &mut BinderScopes<'tcx>,
+        namespace: &'tcx Scope<'tcx>,
+        parent: Option<&Symbol>,
+    ) {
+        self.visit_children(unit, node, scopes, namespace, parent);
+
+        if let Some(type_node) = node.child_by_field(unit, LangRust::field_type)
+            && let Some(type_sym) = resolve_path_symbol(unit, &type_node)
+        {
+            bind_pattern_types(unit, scopes, node, type_sym);
+        }
+
+        if let Some(diagnostic) = check_struct_pattern_fields(unit, node) {
+            tracing::warn!(?diagnostic, "struct pattern diagnostic");
+        }
+    }
+
     // AST: StructName { field1: value1, field2: value2 }
     fn visit_struct_expression(
         &mut self,
@@ -836,6 +942,10 @@ impl<'tcx> AstVisitorRust<'tcx, BinderScopes<'tcx>> for BinderVisitor<'tcx> {
         parent: Option<&Symbol>,
     ) {
         self.visit_children(unit, node, scopes, namespace, parent);
+
+        if let Some(diagnostic) = check_struct_expression_fields(unit, scopes, node) {
+            tracing::warn!(?diagnostic, "struct expression diagnostic");
+        }
     }
 
     // AST: match scrutinee { pattern1 => expr1, pattern2 => expr2 }
@@ -849,6 +959,10 @@ impl<'tcx> AstVisitorRust<'tcx, BinderScopes<'tcx>> for BinderVisitor<'tcx> {
         parent: Option<&Symbol>,
     ) {
         self.visit_children(unit, node, scopes, namespace, parent);
+
+        for diagnostic in check_match_expression(unit, scopes, node) {
+            tracing::warn!(?diagnostic, "match expression diagnostic");
+        }
     }
 
     // AST: match arm body or block in match expression
@@ -822,6 +822,36 @@ impl<'tcx> AstVisitorRust<'tcx, CollectorScopes<'tcx>> for CollectorVisitor<'tcx
         }
     }
 
+    /// AST: for pattern in iterable { body }
+    /// Purpose: Create a scope for the loop variable(s), declare the pattern
+    /// as variable(s) so the body block (which nests its own scope inside
+    /// this one) can resolve them.
+    fn visit_for_expression(
+        &mut self,
+        unit: &CompileUnit<'tcx>,
+        node: &HirNode<'tcx>,
+        scopes: &mut CollectorScopes<'tcx>,
+        namespace: &'tcx Scope<'tcx>,
+        parent: Option<&Symbol>,
+    ) {
+        tracing::trace!("visiting for_expression");
+        if let Some(sn) = node.as_scope() {
+            let scope = unit.cc.alloc_scope(node.id());
+            sn.set_scope(scope);
+
+            scope.add_parent(namespace);
+
+            scopes.push_scope(scope);
+
+            if let Some(pattern) = node.child_by_field(*unit, LangRust::field_pattern) {
+                let _ = Self::collect_pattern_identifiers(unit, &pattern, scopes, SymKind::Variable);
+            }
+
+            self.visit_children(unit, node, scopes, scope, parent);
+            scopes.pop_scope();
+        }
+    }
+
     /// AST: let pattern = value; or let pattern: Type = value; statement
     /// Purpose: Collect pattern identifiers as variables, handle closure special case
     fn visit_let_declaration(
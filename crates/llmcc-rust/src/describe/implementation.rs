@@ -1,10 +1,28 @@
 use llmcc_core::context::CompileUnit;
 use llmcc_core::ir::HirNode;
+use tree_sitter::Node;
 
-use llmcc_descriptor::ImplDescriptor;
+use llmcc_descriptor::{Deprecation, ImplDescriptor, ImplKind, Stability, StabilityLevel, TypeExpr};
 
 use super::function::{build_origin, parse_type_expr};
 
+/// Trait names tree-sitter-rust's std/common derive macros expand to;
+/// matching one of these against a hand-parsed `impl Trait for Type` lets us
+/// tell a derive-synthesized impl from one actually written out by hand.
+const KNOWN_DERIVES: &[&str] = &[
+    "Debug",
+    "Clone",
+    "Copy",
+    "Default",
+    "PartialEq",
+    "Eq",
+    "PartialOrd",
+    "Ord",
+    "Hash",
+    "Serialize",
+    "Deserialize",
+];
+
 /// Build a descriptor for a Rust `impl` block.
 pub fn build<'tcx>(unit: CompileUnit<'tcx>, node: &HirNode<'tcx>) -> Option<ImplDescriptor> {
     let ts_node = match node.inner_ts_node() {
@@ -20,8 +38,118 @@ pub fn build<'tcx>(unit: CompileUnit<'tcx>, node: &HirNode<'tcx>) -> Option<Impl
 
     if let Some(trait_node) = ts_node.child_by_field_name("trait") {
         let trait_ty = parse_type_expr(unit, trait_node);
+        descriptor.kind = match derive_macro_path(unit, ts_node, &descriptor.target_ty, &trait_ty)
+        {
+            Some(macro_path) => ImplKind::Derived { macro_path },
+            None => ImplKind::HandWrittenTrait,
+        };
         descriptor.trait_ty = Some(trait_ty);
     }
 
+    for text in impl_attribute_texts(unit, ts_node) {
+        if descriptor.deprecation.is_none() {
+            descriptor.deprecation = parse_deprecation(&text);
+        }
+        if descriptor.stability.is_none() {
+            descriptor.stability = parse_stability(&text);
+        }
+    }
+
     Some(descriptor)
 }
+
+/// Text of the `attribute_item` siblings directly preceding `ts_node` - the
+/// attributes attached to this impl block itself.
+fn impl_attribute_texts<'tcx>(unit: CompileUnit<'tcx>, ts_node: Node<'tcx>) -> Vec<String> {
+    let Some(parent) = ts_node.parent() else {
+        return Vec::new();
+    };
+    let mut cursor = parent.walk();
+    let siblings: Vec<Node<'tcx>> = parent.named_children(&mut cursor).collect();
+    let Some(index) = siblings.iter().position(|sibling| *sibling == ts_node) else {
+        return Vec::new();
+    };
+
+    siblings[..index]
+        .iter()
+        .rev()
+        .take_while(|sibling| sibling.kind() == "attribute_item")
+        .map(|sibling| node_text(unit, *sibling))
+        .collect()
+}
+
+/// Parse a `#[deprecated(since = "...", note = "...")]` attribute; both
+/// fields are optional in real Rust, so a bare `#[deprecated]` still counts.
+fn parse_deprecation(text: &str) -> Option<Deprecation> {
+    if !text.contains("deprecated") {
+        return None;
+    }
+    Some(Deprecation {
+        since: extract_quoted(text, "since"),
+        note: extract_quoted(text, "note"),
+    })
+}
+
+/// Parse a `#[stable(feature = "...")]`/`#[unstable(feature = "...")]`
+/// attribute.
+fn parse_stability(text: &str) -> Option<Stability> {
+    if text.contains("unstable") {
+        Some(Stability {
+            level: StabilityLevel::Unstable,
+            feature: extract_quoted(text, "feature"),
+        })
+    } else if text.contains("stable") {
+        Some(Stability {
+            level: StabilityLevel::Stable,
+            feature: extract_quoted(text, "feature"),
+        })
+    } else {
+        None
+    }
+}
+
+/// Find `key = "value"` in `text` and return `value`.
+fn extract_quoted(text: &str, key: &str) -> Option<String> {
+    let needle = format!("{key} = \"");
+    let start = text.find(&needle)? + needle.len();
+    let end = start + text[start..].find('"')?;
+    Some(text[start..end].to_string())
+}
+
+/// If `target_ty` is declared elsewhere in the same source with a
+/// `#[derive(...)]` attribute covering `trait_ty`'s name, return the
+/// synthesized macro path (e.g. `"derive(Clone)"`) - otherwise `None`.
+fn derive_macro_path<'tcx>(
+    unit: CompileUnit<'tcx>,
+    ts_node: Node<'tcx>,
+    target_ty: &TypeExpr,
+    trait_ty: &TypeExpr,
+) -> Option<String> {
+    let target_name = target_ty.path_segments()?.last()?;
+    let trait_name = trait_ty.path_segments()?.last()?;
+    if !KNOWN_DERIVES.contains(&trait_name.as_str()) {
+        return None;
+    }
+
+    let parent = ts_node.parent()?;
+    let mut cursor = parent.walk();
+    let siblings: Vec<Node<'tcx>> = parent.named_children(&mut cursor).collect();
+    let target_index = siblings.iter().position(|sibling| {
+        matches!(sibling.kind(), "struct_item" | "enum_item")
+            && sibling
+                .child_by_field_name("name")
+                .is_some_and(|name| node_text(unit, name) == *target_name)
+    })?;
+
+    siblings[..target_index]
+        .iter()
+        .rev()
+        .take_while(|sibling| sibling.kind() == "attribute_item")
+        .map(|sibling| node_text(unit, *sibling))
+        .find(|text| text.contains("derive") && text.contains(trait_name.as_str()))
+        .map(|_| format!("derive({trait_name})"))
+}
+
+fn node_text<'tcx>(unit: CompileUnit<'tcx>, node: Node<'tcx>) -> String {
+    unit.file().get_text(node.start_byte(), node.end_byte())
+}
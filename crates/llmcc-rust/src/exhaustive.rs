@@ -0,0 +1,363 @@
+//! Match-arm exhaustiveness and unreachable-arm diagnostics.
+//!
+//! Implements the usefulness algorithm used by real pattern-match checkers:
+//! maintain a matrix `P` of pattern rows already seen, and ask whether a new
+//! row is *useful* against `P` - is there a value it matches that no row of
+//! `P` matches? Usefulness is computed by specialization: for a constructor
+//! `c`, keep the rows whose head matches `c`, expand `c`'s own fields into new
+//! leading columns, and recurse. An arm is unreachable if its row isn't
+//! useful against every earlier (non-guarded) row; the match is
+//! non-exhaustive if a synthetic wildcard row is useful against every arm.
+//!
+//! Scope: models literals, tuples, and enum/tuple-struct variants by arity and
+//! field type - the constructors this binder already tracks via
+//! `nested_types`. Range patterns (`lo..hi`, `lo..=hi`) are modeled the same
+//! way as literal/const patterns: a non-binding constructor keyed by exact
+//! source text, so a duplicate range arm is still caught, but overlap between
+//! *different* ranges isn't - this binder has no interval algebra. Struct-
+//! pattern fields and slice patterns are treated as opaque wildcards rather
+//! than fully split, since the binder has no field-order-independent
+//! matching.
+
+use llmcc_core::context::CompileUnit;
+use llmcc_core::ir::HirNode;
+use llmcc_core::symbol::{SymId, SymKind};
+use llmcc_resolver::BinderScopes;
+
+use crate::infer::{infer_type, resolve_path_symbol};
+use crate::token::LangRust;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Ctor {
+    Variant(SymId, usize),
+    Literal(String),
+    Tuple(usize),
+}
+
+impl Ctor {
+    fn arity(&self) -> usize {
+        match self {
+            Ctor::Variant(_, arity) | Ctor::Tuple(arity) => *arity,
+            Ctor::Literal(_) => 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Pat {
+    Wildcard,
+    Ctor(Ctor, Vec<Pat>),
+}
+
+/// A single finding from [`check_match_expression`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatchDiagnostic {
+    /// No arm covers every possible value; `missing` names the uncovered
+    /// constructors (enum variant names, or `"_"` when the scrutinee's type
+    /// isn't a closed enum we can enumerate).
+    NonExhaustive { missing: Vec<String> },
+    /// The arm at `arm_index` can never match: every value it covers is
+    /// already covered by an earlier arm.
+    UnreachableArm { arm_index: usize },
+}
+
+/// Check a `match` expression's arms for exhaustiveness and reachability.
+#[tracing::instrument(skip_all)]
+pub fn check_match_expression<'tcx>(
+    unit: &CompileUnit<'tcx>,
+    scopes: &BinderScopes<'tcx>,
+    node: &HirNode<'tcx>,
+) -> Vec<MatchDiagnostic> {
+    let mut diagnostics = Vec::new();
+
+    let Some(body) = node.child_by_field(unit, LangRust::field_body) else {
+        return diagnostics;
+    };
+
+    let arms: Vec<_> = body.children(unit).into_iter().filter(|child| !child.is_trivia()).collect();
+
+    let mut matrix: Vec<Vec<Pat>> = Vec::new();
+
+    for (arm_index, arm) in arms.iter().enumerate() {
+        let Some(pattern_node) = arm.child_by_field_recursive(unit, LangRust::field_pattern) else {
+            continue;
+        };
+        let guarded = arm_has_guard(unit, arm);
+
+        // An or-pattern arm (`2 | 3 => ..`) is unreachable only if *every*
+        // alternative is non-useful; one reachable alternative (`3`) makes
+        // the whole arm reachable even if another (`2`) is already covered.
+        let mut saw_head = false;
+        let mut arm_reachable = false;
+        for head in lower_pattern(unit, scopes, &pattern_node) {
+            saw_head = true;
+            let row = vec![head];
+            if is_useful(unit, &matrix, &row) {
+                arm_reachable = true;
+            }
+            if !guarded {
+                matrix.push(row);
+            }
+        }
+
+        if saw_head && !arm_reachable {
+            diagnostics.push(MatchDiagnostic::UnreachableArm { arm_index });
+        }
+    }
+
+    if is_useful(unit, &matrix, &[Pat::Wildcard]) {
+        let missing = missing_constructors(unit, scopes, &matrix, node);
+        diagnostics.push(MatchDiagnostic::NonExhaustive { missing });
+    }
+
+    diagnostics
+}
+
+/// An arm is guarded if it has a child besides its pattern and its value
+/// (the `if condition` of a `pattern if condition => value` arm). Guarded
+/// arms are still checked for their own reachability, but never narrow
+/// coverage for later arms, since the guard may not hold.
+fn arm_has_guard<'tcx>(unit: &CompileUnit<'tcx>, arm: &HirNode<'tcx>) -> bool {
+    let pattern_id = arm.child_by_field(unit, LangRust::field_pattern).map(|n| n.id());
+    let value_id = arm.child_by_field(unit, LangRust::field_value).map(|n| n.id());
+
+    arm.children(unit)
+        .into_iter()
+        .filter(|child| !child.is_trivia())
+        .any(|child| Some(child.id()) != pattern_id && Some(child.id()) != value_id)
+}
+
+fn lower_pattern<'tcx>(unit: &CompileUnit<'tcx>, scopes: &BinderScopes<'tcx>, node: &HirNode<'tcx>) -> Vec<Pat> {
+    match node.kind_id() {
+        LangRust::or_pattern => node
+            .children(unit)
+            .into_iter()
+            .filter(|child| !child.is_trivia())
+            .flat_map(|child| lower_pattern(unit, scopes, &child))
+            .collect(),
+
+        LangRust::mut_pattern | LangRust::ref_pattern | LangRust::reference_pattern => node
+            .children(unit)
+            .into_iter()
+            .find(|child| !child.is_trivia())
+            .map(|child| lower_pattern(unit, scopes, &child))
+            .unwrap_or_else(|| vec![Pat::Wildcard]),
+
+        // `name @ subpattern` matches exactly what `subpattern` matches - the
+        // alias doesn't affect which values are covered.
+        LangRust::captured_pattern => node
+            .child_by_field(unit, LangRust::field_pattern)
+            .map(|subpattern| lower_pattern(unit, scopes, &subpattern))
+            .unwrap_or_else(|| vec![Pat::Wildcard]),
+
+        LangRust::tuple_pattern => {
+            let fields = lower_fields(unit, scopes, node, None);
+            vec![Pat::Ctor(Ctor::Tuple(fields.len()), fields)]
+        }
+
+        LangRust::tuple_struct_pattern => {
+            let type_node = node.child_by_field(unit, LangRust::field_type);
+            let variant_sym = type_node.as_ref().and_then(|t| resolve_path_symbol(unit, t));
+            let fields = lower_fields(unit, scopes, node, Some(LangRust::field_type));
+            match variant_sym {
+                Some(sym) => vec![Pat::Ctor(Ctor::Variant(sym.id(), fields.len()), fields)],
+                None => vec![Pat::Wildcard],
+            }
+        }
+
+        LangRust::struct_pattern => {
+            // Field-level coverage isn't modeled; the variant/struct itself
+            // is treated as a zero-arity constructor so enum-variant
+            // coverage is still tracked precisely.
+            let type_node = node.child_by_field(unit, LangRust::field_type);
+            match type_node.as_ref().and_then(|t| resolve_path_symbol(unit, t)) {
+                Some(sym) => vec![Pat::Ctor(Ctor::Variant(sym.id(), 0), Vec::new())],
+                None => vec![Pat::Wildcard],
+            }
+        }
+
+        LangRust::boolean_literal
+        | LangRust::integer_literal
+        | LangRust::float_literal
+        | LangRust::char_literal
+        | LangRust::string_literal => {
+            let text = node.as_text().map(|t| t.text().to_string()).unwrap_or_default();
+            vec![Pat::Ctor(Ctor::Literal(text), Vec::new())]
+        }
+
+        LangRust::identifier => match node.as_ident().and_then(|ident| ident.opt_symbol()) {
+            Some(sym) if sym.kind() == SymKind::Const => {
+                vec![Pat::Ctor(Ctor::Literal(format!("const#{}", sym.id().0)), Vec::new())]
+            }
+            // A bare lowercase identifier that isn't a known const is a
+            // catch-all binding, not a constructor.
+            _ => vec![Pat::Wildcard],
+        },
+
+        // `lo..hi` / `lo..=hi` over integer or char endpoints. Like a
+        // literal/const pattern it binds no names, so it's a zero-arity
+        // constructor keyed by its exact source text - an identical range
+        // arm is still caught as unreachable, though overlap between
+        // differently-written ranges isn't (no interval algebra here).
+        LangRust::range_pattern => {
+            vec![Pat::Ctor(Ctor::Literal(format!("range#{}", unit.hir_text(node))), Vec::new())]
+        }
+
+        // Wildcard ('_'), rest ('..'), slice patterns, and anything else not
+        // modeled above - treated conservatively as a wildcard so we never
+        // report a false unreachable-arm or false exhaustiveness.
+        _ => vec![Pat::Wildcard],
+    }
+}
+
+/// Lower the non-type-path, non-trivia children of a tuple/tuple-struct
+/// pattern into one sub-pattern per positional field.
+fn lower_fields<'tcx>(
+    unit: &CompileUnit<'tcx>,
+    scopes: &BinderScopes<'tcx>,
+    node: &HirNode<'tcx>,
+    skip_field: Option<u16>,
+) -> Vec<Pat> {
+    node.children(unit)
+        .into_iter()
+        .filter(|child| !child.is_trivia() && Some(child.field_id()) != skip_field)
+        .map(|child| lower_pattern(unit, scopes, &child).into_iter().next().unwrap_or(Pat::Wildcard))
+        .collect()
+}
+
+/// Is `row` useful against `matrix` - does it match some value no row of
+/// `matrix` matches?
+fn is_useful<'tcx>(unit: &CompileUnit<'tcx>, matrix: &[Vec<Pat>], row: &[Pat]) -> bool {
+    let Some(head) = row.first() else {
+        return matrix.is_empty();
+    };
+
+    match head {
+        Pat::Wildcard => match head_ctors(unit, matrix) {
+            Some(ctors) => ctors.into_iter().any(|ctor| {
+                let sub_matrix = specialize_matrix(matrix, &ctor);
+                let mut sub_row = vec![Pat::Wildcard; ctor.arity()];
+                sub_row.extend_from_slice(&row[1..]);
+                is_useful(unit, &sub_matrix, &sub_row)
+            }),
+            None => is_useful(unit, &default_matrix(matrix), &row[1..]),
+        },
+        Pat::Ctor(ctor, fields) => {
+            let sub_matrix = specialize_matrix(matrix, ctor);
+            let mut sub_row = fields.clone();
+            sub_row.extend_from_slice(&row[1..]);
+            is_useful(unit, &sub_matrix, &sub_row)
+        }
+    }
+}
+
+/// The distinct constructors appearing in column 0 of `matrix`, if they form
+/// a *complete* case split for their type - in which case checking each of
+/// them is precise. `None` means the split is open (some constructor isn't
+/// covered by anything seen so far), so callers should fall back to the
+/// default matrix instead.
+fn head_ctors<'tcx>(unit: &CompileUnit<'tcx>, matrix: &[Vec<Pat>]) -> Option<Vec<Ctor>> {
+    let mut seen: Vec<Ctor> = Vec::new();
+    for row in matrix {
+        if let Some(Pat::Ctor(ctor, _)) = row.first()
+            && !seen.contains(ctor)
+        {
+            seen.push(ctor.clone());
+        }
+    }
+
+    let complete = match seen.first()? {
+        Ctor::Tuple(_) => true, // a tuple/struct shape is the only constructor for its type
+        Ctor::Variant(sym_id, _) => variant_sibling_count(unit, *sym_id) == Some(seen.len()),
+        Ctor::Literal(text) if text == "true" || text == "false" => seen.len() == 2,
+        Ctor::Literal(_) => false,
+    };
+
+    complete.then_some(seen)
+}
+
+fn specialize_matrix(matrix: &[Vec<Pat>], ctor: &Ctor) -> Vec<Vec<Pat>> {
+    matrix.iter().filter_map(|row| specialize_row(row, ctor)).collect()
+}
+
+fn specialize_row(row: &[Pat], ctor: &Ctor) -> Option<Vec<Pat>> {
+    let (head, rest) = row.split_first()?;
+    match head {
+        Pat::Wildcard => {
+            let mut new_row = vec![Pat::Wildcard; ctor.arity()];
+            new_row.extend_from_slice(rest);
+            Some(new_row)
+        }
+        Pat::Ctor(head_ctor, fields) if head_ctor == ctor => {
+            let mut new_row = fields.clone();
+            new_row.extend_from_slice(rest);
+            Some(new_row)
+        }
+        Pat::Ctor(_, _) => None,
+    }
+}
+
+fn default_matrix(matrix: &[Vec<Pat>]) -> Vec<Vec<Pat>> {
+    matrix
+        .iter()
+        .filter_map(|row| {
+            let (head, rest) = row.split_first()?;
+            match head {
+                Pat::Wildcard => Some(rest.to_vec()),
+                Pat::Ctor(_, _) => None,
+            }
+        })
+        .collect()
+}
+
+/// Total number of variants declared in `variant_id`'s enclosing enum.
+fn variant_sibling_count<'tcx>(unit: &CompileUnit<'tcx>, variant_id: SymId) -> Option<usize> {
+    let variant_sym = unit.opt_get_symbol(variant_id)?;
+    let enum_sym = unit.opt_get_symbol(variant_sym.type_of()?)?;
+    let scope = unit.get_scope(enum_sym.opt_scope()?);
+
+    let mut count = 0;
+    scope.for_each_symbol(|sym| {
+        if sym.kind() == SymKind::EnumVariant {
+            count += 1;
+        }
+    });
+    Some(count)
+}
+
+/// Name the enum variants (if the scrutinee's type is a known enum) that no
+/// arm in `matrix` covers, for a [`MatchDiagnostic::NonExhaustive`] witness.
+fn missing_constructors<'tcx>(
+    unit: &CompileUnit<'tcx>,
+    scopes: &BinderScopes<'tcx>,
+    matrix: &[Vec<Pat>],
+    match_node: &HirNode<'tcx>,
+) -> Vec<String> {
+    let seen: Vec<SymId> = matrix
+        .iter()
+        .filter_map(|row| match row.first() {
+            Some(Pat::Ctor(Ctor::Variant(sym_id, _), _)) => Some(*sym_id),
+            _ => None,
+        })
+        .collect();
+
+    let scrutinee_enum = match_node
+        .child_by_field(unit, LangRust::field_value)
+        .and_then(|value_node| infer_type(unit, scopes, &value_node))
+        .filter(|sym| sym.kind() == SymKind::Enum);
+
+    let Some(enum_sym) = scrutinee_enum else {
+        return vec!["_".to_string()];
+    };
+    let Some(scope_id) = enum_sym.opt_scope() else {
+        return vec!["_".to_string()];
+    };
+
+    let mut missing = Vec::new();
+    unit.get_scope(scope_id).for_each_symbol(|sym| {
+        if sym.kind() == SymKind::EnumVariant && !seen.contains(&sym.id()) {
+            missing.push(unit.resolve_name(sym.name));
+        }
+    });
+    missing
+}
@@ -186,7 +186,15 @@ fn infer_array_expression<'tcx>(
     infer_from_children(unit, scopes, node, &[LangRust::field_length])
 }
 
-/// Infer range expression type: 1..5 -> Range<i32>
+/// Infer range expression type: covers all six range forms (`..`, `a..`,
+/// `..b`, `a..b`, `..=b`, `a..=b`). Returns the endpoint's type `T` (the first
+/// operand found, left-to-right) rather than a synthesized `RangeFull` /
+/// `RangeFrom<T>` / `RangeTo<T>` / `Range<T>` / `RangeToInclusive<T>` /
+/// `RangeInclusive<T>` wrapper, since this binder has no mechanism to
+/// synthesize parameterized standard-library type symbols (the same
+/// limitation `infer_array_expression` has for `[T; N]`). Falls back to
+/// `i32` whenever no operand yields a type (always the case for an
+/// unbounded `..`), matching the default integer literal type used elsewhere.
 fn infer_range_expression<'tcx>(
     unit: &CompileUnit<'tcx>,
     scopes: &BinderScopes<'tcx>,
@@ -299,6 +307,24 @@ fn infer_scoped_identifier<'tcx>(
         .copied()
 }
 
+/// Resolve the symbol bound to the final segment of a path node - a bare
+/// `identifier`, or the `name` field of a `scoped_identifier`/`scoped_type_identifier`
+/// (e.g. `Succ` in `Nat::Succ`). Unlike `infer_type`, this does not follow
+/// `type_of`, so it returns the path's own symbol (an enum variant, say)
+/// rather than the type that symbol was declared with.
+#[tracing::instrument(skip_all)]
+pub(crate) fn resolve_path_symbol<'tcx>(
+    unit: &CompileUnit<'tcx>,
+    node: &HirNode<'tcx>,
+) -> Option<&'tcx Symbol> {
+    if node.is_kind(HirKind::Identifier) {
+        return node.as_ident()?.opt_symbol();
+    }
+    node.ident_by_field(unit, LangRust::field_name)
+        .or_else(|| node.find_ident(unit))?
+        .opt_symbol()
+}
+
 /// Infer index expression type: arr[i] -> ElementType
 fn infer_index_expression<'tcx>(
     unit: &CompileUnit<'tcx>,
@@ -429,6 +455,22 @@ fn infer_reference_type<'tcx>(
         .and_then(|type_node| infer_type(unit, scopes, &type_node))
 }
 
+/// Resolve the element type yielded by iterating `iterable_node` - the `Item`
+/// type of a `for pattern in iterable` loop. Only types that already expose an
+/// element via `nested_types` (arrays/slices, and references to them) are
+/// supported; other iterables (e.g. `Vec<T>`, or a user `Iterator` impl)
+/// aren't modeled, since this binder has no generic-container symbol to hang
+/// `nested_types` off yet.
+pub(crate) fn infer_iterable_element_type<'tcx>(
+    unit: &CompileUnit<'tcx>,
+    scopes: &BinderScopes<'tcx>,
+    iterable_node: &HirNode<'tcx>,
+) -> Option<&'tcx Symbol> {
+    let iterable_type = infer_type(unit, scopes, iterable_node)?;
+    let element_id = iterable_type.nested_types()?.first().copied()?;
+    unit.opt_get_symbol(element_id)
+}
+
 /// Infer pointer type annotation: *const T or *mut T
 fn infer_pointer_type<'tcx>(
     unit: &CompileUnit<'tcx>,
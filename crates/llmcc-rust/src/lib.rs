@@ -3,9 +3,10 @@ extern crate llmcc_core;
 
 mod bind;
 mod collect;
-mod pattern;
+pub mod exhaustive;
+mod infer;
+pub mod pattern;
 pub mod token;
-mod ty;
 mod util;
 
 pub const RUST_PRIMITIVES: &[&str] = &[
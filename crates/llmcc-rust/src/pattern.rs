@@ -5,9 +5,106 @@ use llmcc_core::ir::HirNode;
 use llmcc_core::symbol::{SymKind, Symbol};
 use llmcc_resolver::BinderScopes;
 
-use crate::infer::infer_type;
+use crate::infer::{infer_type, resolve_path_symbol};
 use crate::token::LangRust;
 
+/// Fields a struct pattern or struct literal declares but doesn't mention,
+/// when it also has no `..` (pattern) / base-struct spread (literal) to
+/// explicitly opt out of the rest. See [`check_struct_pattern_fields`] and
+/// [`check_struct_expression_fields`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingFieldsDiagnostic {
+    pub missing: Vec<String>,
+}
+
+/// Check a struct pattern - `Point { x }` - for declared fields it omits
+/// without a trailing `..` rest. Mirrors `assign_type_to_struct_pattern`'s
+/// own field-name resolution so the two stay in sync.
+#[tracing::instrument(skip_all)]
+pub fn check_struct_pattern_fields<'tcx>(
+    unit: &CompileUnit<'tcx>,
+    pattern: &HirNode<'tcx>,
+) -> Option<MissingFieldsDiagnostic> {
+    let type_node = pattern.child_by_field(unit, LangRust::field_type)?;
+    let struct_symbol = resolve_path_symbol(unit, &type_node)?;
+
+    let mut mentioned = Vec::new();
+    for child in pattern.children(unit) {
+        if child.kind_id() == LangRust::remaining_field_pattern {
+            // `..` rest - whatever isn't named is explicitly opted out of.
+            return None;
+        }
+        if child.kind_id() == LangRust::field_pattern
+            && let Some(field_name_node) = child.child_by_field(unit, LangRust::field_name)
+            && let Some(field_name_ident) = field_name_node.find_ident(unit)
+        {
+            mentioned.push(field_name_ident.name.clone());
+        }
+    }
+
+    missing_fields(unit, struct_symbol, &mentioned)
+}
+
+/// Check a struct literal - `Config { value: 42 }` - for declared fields it
+/// omits without a `..base` spread.
+#[tracing::instrument(skip_all)]
+pub fn check_struct_expression_fields<'tcx>(
+    unit: &CompileUnit<'tcx>,
+    scopes: &BinderScopes<'tcx>,
+    node: &HirNode<'tcx>,
+) -> Option<MissingFieldsDiagnostic> {
+    let name_node = node.child_by_field(unit, LangRust::field_name)?;
+    let struct_symbol = infer_type(unit, scopes, &name_node)?;
+
+    let mut mentioned = Vec::new();
+    for child in node.children(unit) {
+        match child.kind_id() {
+            LangRust::base_field_initializer => {
+                // `..base` - remaining fields come from `base`.
+                return None;
+            }
+            LangRust::field_initializer => {
+                if let Some(field_name_ident) = child
+                    .child_by_field(unit, LangRust::field_name)
+                    .and_then(|n| n.find_ident(unit))
+                {
+                    mentioned.push(field_name_ident.name.clone());
+                }
+            }
+            LangRust::shorthand_field_initializer => {
+                if let Some(ident) = child.as_ident() {
+                    mentioned.push(ident.name.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    missing_fields(unit, struct_symbol, &mentioned)
+}
+
+/// Diff `struct_symbol`'s declared `SymKind::Field` members against
+/// `mentioned`, returning the names present in the former but not the latter.
+fn missing_fields<'tcx>(
+    unit: &CompileUnit<'tcx>,
+    struct_symbol: &'tcx Symbol,
+    mentioned: &[String],
+) -> Option<MissingFieldsDiagnostic> {
+    let scope = unit.get_scope(struct_symbol.opt_scope()?);
+
+    let mut missing = Vec::new();
+    scope.for_each_symbol(|sym| {
+        if sym.kind() == SymKind::Field {
+            let name = unit.resolve_name(sym.name);
+            if !mentioned.contains(&name) {
+                missing.push(name);
+            }
+        }
+    });
+
+    (!missing.is_empty()).then_some(MissingFieldsDiagnostic { missing })
+}
+
 #[tracing::instrument(skip_all)]
 pub fn bind_pattern_types<'tcx>(
     unit: &CompileUnit<'tcx>,
@@ -60,6 +157,14 @@ pub fn bind_pattern_types<'tcx>(
                 bind_pattern_types(unit, scopes, inner, pattern_type);
             }
         }
+        // AST: name @ subpattern
+        LangRust::captured_pattern => {
+            assign_type_to_captured_pattern(unit, scopes, pattern, pattern_type);
+        }
+        // AST: lo..hi or lo..=hi - binds no names, so there's nothing to
+        // assign a type to; its endpoints are already-resolved literals or
+        // consts, not fresh bindings.
+        LangRust::range_pattern => {}
         _ => {
             // Handle other patterns - find and assign to any identifiers
             if let Some(ident) = pattern.find_ident(unit) {
@@ -155,7 +260,13 @@ fn bind_tuple_type_to_pattern<'tcx>(
 }
 
 /// AST: (pattern1, pattern2, pattern3)
-/// Assign tuple element types to each pattern
+/// Assign tuple element types to each pattern. When an element pattern is
+/// itself composite (a nested tuple/struct/tuple-struct pattern), `bind_pattern_types`
+/// dispatches back into the matching `assign_type_to_*` handler with that
+/// element's component type, so nesting of arbitrary depth (`((a, b), c)`)
+/// resolves positionally at each level. If the component type's arity is
+/// shorter than the pattern's (a mismatched annotation), extra elements are
+/// simply left untyped rather than panicking.
 #[tracing::instrument(skip_all)]
 fn assign_type_to_tuple_pattern<'tcx>(
     unit: &CompileUnit<'tcx>,
@@ -203,15 +314,9 @@ fn assign_type_to_struct_pattern<'tcx>(
         }
     };
 
-    let struct_type_ident = match struct_type_node.find_ident(unit) {
-        Some(ident) => ident,
-        None => {
-            tracing::trace!("struct type node missing identifier");
-            return;
-        }
-    };
-
-    let struct_symbol = match struct_type_ident.opt_symbol() {
+    // Resolve the tail segment of the path, so qualified enum-variant struct
+    // patterns (`Nat::Node { .. }`) bind against the variant, not the enum.
+    let struct_symbol = match resolve_path_symbol(unit, &struct_type_node) {
         Some(sym) => sym,
         None => {
             tracing::trace!("struct type identifier has no symbol");
@@ -295,12 +400,9 @@ fn assign_type_to_tuple_struct_pattern<'tcx>(
         None => return,
     };
 
-    let type_ident = match type_node.find_ident(unit) {
-        Some(ident) => ident,
-        None => return,
-    };
-
-    let type_symbol = match type_ident.opt_symbol() {
+    // Resolve the tail segment of the path, so qualified enum-variant tuple
+    // patterns (`Nat::Succ(x)`) bind against the variant, not the enum.
+    let type_symbol = match resolve_path_symbol(unit, &type_node) {
         Some(sym) => sym,
         None => return,
     };
@@ -387,6 +489,29 @@ fn assign_type_to_slice_pattern<'tcx>(
     tracing::trace!("assigned element type to slice pattern elements");
 }
 
+/// AST: name @ subpattern
+/// The alias binds the whole matched value, so it gets `pattern_type`
+/// directly; the subpattern is then bound against the same type so it can
+/// further destructure or constrain it (e.g. `Point { x: px @ _, .. }` binds
+/// both the struct field destructure and the `@`-alias).
+#[tracing::instrument(skip_all)]
+fn assign_type_to_captured_pattern<'tcx>(
+    unit: &CompileUnit<'tcx>,
+    scopes: &mut BinderScopes<'tcx>,
+    pattern: &HirNode<'tcx>,
+    pattern_type: &'tcx Symbol,
+) {
+    if let Some(name_ident) = pattern.ident_by_field(unit, LangRust::field_name) {
+        assign_type_to_ident(unit, scopes, name_ident, pattern_type);
+    }
+
+    if let Some(subpattern) = pattern.child_by_field(unit, LangRust::field_pattern) {
+        bind_pattern_types(unit, scopes, &subpattern, pattern_type);
+    }
+
+    tracing::trace!("assigned type to captured (@) pattern");
+}
+
 /// AST: &pattern or &mut pattern
 /// Get the dereferenced type
 #[tracing::instrument(skip_all)]
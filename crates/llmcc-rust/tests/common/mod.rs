@@ -57,6 +57,72 @@ pub fn find_symbol_id<'a>(cc: &'a CompileCtxt<'a>, name: &str, kind: SymKind) ->
         .unwrap_or_else(|| panic!("symbol {name} with kind {:?} not found", kind))
 }
 
+/// Expected shape of a bound symbol, built fluently and checked by `assert_bind_symbol`.
+#[allow(dead_code)]
+pub struct BindExpect {
+    kind: SymKind,
+    type_of: Option<String>,
+    expect_scope: bool,
+}
+
+impl BindExpect {
+    #[allow(dead_code)]
+    pub fn new(kind: SymKind) -> Self {
+        Self {
+            kind,
+            type_of: None,
+            expect_scope: false,
+        }
+    }
+
+    /// Assert the symbol's `type_of` resolves to a symbol named `name`.
+    #[allow(dead_code)]
+    pub fn with_type_of(mut self, name: &str) -> Self {
+        self.type_of = Some(name.to_string());
+        self
+    }
+
+    /// Assert the symbol has an associated scope (e.g. struct/fn bodies).
+    #[allow(dead_code)]
+    pub fn expect_scope(mut self) -> Self {
+        self.expect_scope = true;
+        self
+    }
+}
+
+/// Find `name`/`kind` among the bound symbols and check it against `expect`.
+#[allow(dead_code)]
+pub fn assert_bind_symbol<'a>(cc: &'a CompileCtxt<'a>, name: &str, expect: BindExpect) {
+    let name_key = cc.interner.intern(name);
+    let symbol = cc
+        .get_all_symbols()
+        .into_iter()
+        .find(|sym| sym.name == name_key && sym.kind() == expect.kind)
+        .unwrap_or_else(|| panic!("symbol {name} with kind {:?} not found", expect.kind));
+
+    if let Some(expected_type) = &expect.type_of {
+        let type_symbol = symbol
+            .type_of()
+            .and_then(|type_id| cc.opt_get_symbol(type_id))
+            .unwrap_or_else(|| panic!("symbol {name} has no type_of, expected '{expected_type}'"));
+        let type_name = cc
+            .interner
+            .resolve_owned(type_symbol.name)
+            .unwrap_or_default();
+        assert_eq!(
+            &type_name, expected_type,
+            "symbol {name} expected type '{expected_type}', got '{type_name}'"
+        );
+    }
+
+    if expect.expect_scope {
+        assert!(
+            symbol.opt_scope().is_some(),
+            "symbol {name} expected to have an associated scope"
+        );
+    }
+}
+
 #[allow(dead_code)]
 pub fn assert_exists<'a>(cc: &'a CompileCtxt<'a>, name: &str, kind: SymKind) {
     let name_key = cc.interner.intern(name);
@@ -0,0 +1,281 @@
+mod common;
+
+use common::with_compiled_unit;
+use llmcc_core::context::CompileUnit;
+use llmcc_core::ir::HirNode;
+use llmcc_resolver::BinderScopes;
+use llmcc_rust::exhaustive::{MatchDiagnostic, check_match_expression};
+use llmcc_rust::token::LangRust;
+use serial_test::serial;
+use textwrap::dedent;
+
+// ==============================================================================
+// Unit tests for exhaustive.rs - check_match_expression
+// ==============================================================================
+
+fn find_kind<'tcx>(unit: &CompileUnit<'tcx>, node: &HirNode<'tcx>, kind_id: u16) -> Option<HirNode<'tcx>> {
+    if node.kind_id() == kind_id {
+        return Some(*node);
+    }
+    for child in node.children(unit) {
+        if let Some(found) = find_kind(unit, &child, kind_id) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+fn check_first_match<'tcx>(cc: &'tcx llmcc_core::CompileCtxt<'tcx>) -> Vec<MatchDiagnostic> {
+    let unit = cc.compile_unit(0);
+    let root = unit.hir_node(unit.file_root_id().expect("file root"));
+    let match_node = find_kind(&unit, &root, LangRust::match_expression).expect("match expression");
+    let globals = cc.create_globals();
+    let scopes = BinderScopes::new(unit, globals);
+    check_match_expression(&unit, &scopes, &match_node)
+}
+
+#[serial]
+#[test]
+fn exhaustive_match_reports_nothing() {
+    let source = dedent(
+        "
+        enum Nat {
+            Zero,
+            Succ(i32),
+        }
+
+        fn main() {
+            let n = Nat::Succ(1);
+            match n {
+                Nat::Zero => {}
+                Nat::Succ(_) => {}
+            }
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        let diagnostics = check_first_match(cc);
+        assert!(diagnostics.is_empty(), "expected no diagnostics, got {diagnostics:?}");
+    });
+}
+
+#[serial]
+#[test]
+fn non_exhaustive_match_names_missing_variant() {
+    let source = dedent(
+        "
+        enum Nat {
+            Zero,
+            Succ(i32),
+        }
+
+        fn main() {
+            let n = Nat::Succ(1);
+            match n {
+                Nat::Zero => {}
+            }
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        let diagnostics = check_first_match(cc);
+        assert_eq!(
+            diagnostics,
+            vec![MatchDiagnostic::NonExhaustive {
+                missing: vec!["Succ".to_string()]
+            }]
+        );
+    });
+}
+
+#[serial]
+#[test]
+fn wildcard_arm_makes_match_exhaustive() {
+    let source = dedent(
+        "
+        enum Nat {
+            Zero,
+            Succ(i32),
+        }
+
+        fn main() {
+            let n = Nat::Succ(1);
+            match n {
+                Nat::Zero => {}
+                _ => {}
+            }
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        let diagnostics = check_first_match(cc);
+        assert!(diagnostics.is_empty(), "expected no diagnostics, got {diagnostics:?}");
+    });
+}
+
+#[serial]
+#[test]
+fn duplicate_range_arm_is_unreachable() {
+    let source = dedent(
+        "
+        fn main() {
+            let x = 1;
+            match x {
+                1..=5 => {}
+                1..=5 => {}
+                _ => {}
+            }
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        let diagnostics = check_first_match(cc);
+        assert!(diagnostics.contains(&MatchDiagnostic::UnreachableArm { arm_index: 1 }));
+    });
+}
+
+#[serial]
+#[test]
+fn distinct_range_arms_are_each_reachable() {
+    let source = dedent(
+        "
+        fn main() {
+            let x = 1;
+            match x {
+                1..=5 => {}
+                6..=10 => {}
+                _ => {}
+            }
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        let diagnostics = check_first_match(cc);
+        assert!(diagnostics.is_empty(), "expected no diagnostics, got {diagnostics:?}");
+    });
+}
+
+#[serial]
+#[test]
+fn arm_after_wildcard_is_unreachable() {
+    let source = dedent(
+        "
+        enum Nat {
+            Zero,
+            Succ(i32),
+        }
+
+        fn main() {
+            let n = Nat::Succ(1);
+            match n {
+                _ => {}
+                Nat::Zero => {}
+            }
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        let diagnostics = check_first_match(cc);
+        assert!(diagnostics.contains(&MatchDiagnostic::UnreachableArm { arm_index: 1 }));
+    });
+}
+
+#[serial]
+#[test]
+fn duplicate_literal_arm_is_unreachable() {
+    let source = dedent(
+        "
+        fn main() {
+            let x = 1;
+            match x {
+                1 => {}
+                1 => {}
+                _ => {}
+            }
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        let diagnostics = check_first_match(cc);
+        assert!(diagnostics.contains(&MatchDiagnostic::UnreachableArm { arm_index: 1 }));
+    });
+}
+
+#[serial]
+#[test]
+fn or_pattern_covers_every_alternative() {
+    let source = dedent(
+        "
+        fn main() {
+            let x = 1;
+            match x {
+                1 | 2 | 3 => {}
+                1 => {}
+                _ => {}
+            }
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        let diagnostics = check_first_match(cc);
+        assert!(diagnostics.contains(&MatchDiagnostic::UnreachableArm { arm_index: 1 }));
+    });
+}
+
+#[serial]
+#[test]
+fn or_pattern_is_reachable_if_any_alternative_is_not_yet_covered() {
+    // `2` is already covered by the first arm, but `3` isn't - the arm as a
+    // whole is still reachable via `3` and must not be flagged.
+    let source = dedent(
+        "
+        fn main() {
+            let x = 1;
+            match x {
+                2 => {}
+                2 | 3 => {}
+                _ => {}
+            }
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        let diagnostics = check_first_match(cc);
+        assert!(
+            !diagnostics.contains(&MatchDiagnostic::UnreachableArm { arm_index: 1 }),
+            "expected arm 1 to be reachable via its `3` alternative, got {diagnostics:?}"
+        );
+    });
+}
+
+#[serial]
+#[test]
+fn guarded_arm_does_not_narrow_later_coverage() {
+    // A guarded arm is checked for its own reachability but never removes
+    // coverage from the matrix, since the guard might not hold at runtime.
+    let source = dedent(
+        "
+        fn main() {
+            let x = 1;
+            match x {
+                n if n > 0 => {}
+                n => {}
+            }
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        let diagnostics = check_first_match(cc);
+        assert!(diagnostics.is_empty(), "expected no diagnostics, got {diagnostics:?}");
+    });
+}
@@ -61,16 +61,15 @@ fn test_pattern_tuple() {
     });
 }
 
-// Tests for nested tuple pattern - verifies variables are collected
+// Tests for nested tuple pattern - verifies recursive composite-type propagation
 #[serial]
 #[test]
 fn test_pattern_tuple_nested() {
-    // Tests: assign_type_to_tuple_pattern - nested tuple pattern
-    // Note: Nested type resolution requires recursive CompositeType lookup
+    // Tests: assign_type_to_tuple_pattern - nested tuple pattern, type annotated
     let source = dedent(
         "
         fn main() {
-            let ((x, y), z) = ((1, 2), true);
+            let ((x, y), z): ((i32, i64), bool) = ((1, 2), true);
             drop(x);
             drop(y);
             drop(z);
@@ -79,10 +78,21 @@ fn test_pattern_tuple_nested() {
     );
 
     with_compiled_unit(&[&source], |cc| {
-        // Verify all variables are collected
-        assert_bind_symbol(cc, "x", BindExpect::new(SymKind::Variable));
-        assert_bind_symbol(cc, "y", BindExpect::new(SymKind::Variable));
-        assert_bind_symbol(cc, "z", BindExpect::new(SymKind::Variable));
+        assert_bind_symbol(
+            cc,
+            "x",
+            BindExpect::new(SymKind::Variable).with_type_of("i32"),
+        );
+        assert_bind_symbol(
+            cc,
+            "y",
+            BindExpect::new(SymKind::Variable).with_type_of("i64"),
+        );
+        assert_bind_symbol(
+            cc,
+            "z",
+            BindExpect::new(SymKind::Variable).with_type_of("bool"),
+        );
     });
 }
 
@@ -416,11 +426,11 @@ fn test_pattern_const_skip() {
 #[serial]
 #[test]
 fn test_pattern_deep_nesting() {
-    // Tests: deeply nested patterns are traversed
+    // Tests: deeply nested patterns are traversed and typed at every level
     let source = dedent(
         "
         fn main() {
-            let (((a, b), c), d) = (((1, 2), 3), 4);
+            let (((a, b), c), d): (((i32, i32), bool), i32) = (((1, 2), true), 4);
             drop(a);
             drop(b);
             drop(c);
@@ -430,10 +440,26 @@ fn test_pattern_deep_nesting() {
     );
 
     with_compiled_unit(&[&source], |cc| {
-        // Verify all variables are collected
-        for var in &["a", "b", "c", "d"] {
-            assert_bind_symbol(cc, var, BindExpect::new(SymKind::Variable));
-        }
+        assert_bind_symbol(
+            cc,
+            "a",
+            BindExpect::new(SymKind::Variable).with_type_of("i32"),
+        );
+        assert_bind_symbol(
+            cc,
+            "b",
+            BindExpect::new(SymKind::Variable).with_type_of("i32"),
+        );
+        assert_bind_symbol(
+            cc,
+            "c",
+            BindExpect::new(SymKind::Variable).with_type_of("bool"),
+        );
+        assert_bind_symbol(
+            cc,
+            "d",
+            BindExpect::new(SymKind::Variable).with_type_of("i32"),
+        );
     });
 }
 
@@ -467,6 +493,47 @@ fn test_pattern_struct_rest() {
     });
 }
 
+// Tests for a struct field that is itself a composite (tuple) type
+#[serial]
+#[test]
+fn test_pattern_struct_nested_tuple_field() {
+    // Tests: assign_type_to_struct_pattern - recursing into a nested tuple field
+    let source = dedent(
+        "
+        struct Line {
+            start: (i32, i32),
+            flag: bool,
+        }
+
+        fn destruct(l: Line) {
+            let Line { start: (x, y), flag } = l;
+            drop(x);
+            drop(y);
+            drop(flag);
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        assert_bind_symbol(cc, "Line", BindExpect::new(SymKind::Struct).expect_scope());
+        assert_bind_symbol(
+            cc,
+            "x",
+            BindExpect::new(SymKind::Variable).with_type_of("i32"),
+        );
+        assert_bind_symbol(
+            cc,
+            "y",
+            BindExpect::new(SymKind::Variable).with_type_of("i32"),
+        );
+        assert_bind_symbol(
+            cc,
+            "flag",
+            BindExpect::new(SymKind::Variable).with_type_of("bool"),
+        );
+    });
+}
+
 // ==============================================================================
 // Legacy tests (keeping for backwards compatibility)
 // ==============================================================================
@@ -860,3 +927,199 @@ fn bind_pattern_with_enum_variant() {
         assert!(status_sym.0 > 0);
     });
 }
+
+#[serial]
+#[test]
+fn bind_tuple_variant_pattern_payload() {
+    // `Nat::Succ(x)` should bind `x` to the variant's own field type, not the
+    // enclosing enum.
+    let source = dedent(
+        "
+        enum Nat {
+            Zero,
+            Succ(i32),
+        }
+
+        fn main() {
+            let n = Nat::Succ(1);
+            if let Nat::Succ(x) = n {
+                drop(x);
+            }
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        assert_bind_symbol(cc, "x", BindExpect::new(SymKind::Variable).with_type_of("i32"));
+    });
+}
+
+#[serial]
+#[test]
+fn bind_tuple_variant_pattern_multiple_fields() {
+    // Positional binding must line up each pattern element with the
+    // corresponding field type, in declaration order.
+    let source = dedent(
+        "
+        enum Shape {
+            Circle(f64),
+            Rect(i32, bool),
+        }
+
+        fn main() {
+            let s = Shape::Rect(3, true);
+            if let Shape::Rect(width, flag) = s {
+                drop(width);
+                drop(flag);
+            }
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        assert_bind_symbol(cc, "width", BindExpect::new(SymKind::Variable).with_type_of("i32"));
+        assert_bind_symbol(cc, "flag", BindExpect::new(SymKind::Variable).with_type_of("bool"));
+    });
+}
+
+#[serial]
+#[test]
+fn bind_struct_variant_pattern_payload() {
+    // `List::Node { value, flag }` should bind each shorthand field pattern to
+    // the struct-like variant's own field type.
+    let source = dedent(
+        "
+        enum List {
+            Empty,
+            Node { value: i32, flag: bool },
+        }
+
+        fn main() {
+            let l = List::Node { value: 7, flag: true };
+            if let List::Node { value, flag } = l {
+                drop(value);
+                drop(flag);
+            }
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        assert_bind_symbol(cc, "value", BindExpect::new(SymKind::Variable).with_type_of("i32"));
+        assert_bind_symbol(cc, "flag", BindExpect::new(SymKind::Variable).with_type_of("bool"));
+    });
+}
+
+#[serial]
+#[test]
+fn bind_tuple_struct_pattern_payload() {
+    // Plain tuple structs go through the same `ordered_field_declaration_list`
+    // nested_types wiring as tuple enum variants.
+    let source = dedent(
+        "
+        struct Point(i32, i32);
+
+        fn main() {
+            let p = Point(1, 2);
+            let Point(x, y) = p;
+            drop(x);
+            drop(y);
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        assert_bind_symbol(cc, "x", BindExpect::new(SymKind::Variable).with_type_of("i32"));
+        assert_bind_symbol(cc, "y", BindExpect::new(SymKind::Variable).with_type_of("i32"));
+    });
+}
+
+#[serial]
+#[test]
+fn bind_captured_pattern_in_parameter() {
+    // `n @ _: i32` binds `n` to the explicit parameter type via the `@`
+    // alias, composing with the underlying wildcard subpattern.
+    let source = dedent(
+        "
+        fn handle(n @ _: i32) {
+            drop(n);
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        assert_bind_symbol(cc, "n", BindExpect::new(SymKind::Variable).with_type_of("i32"));
+    });
+}
+
+#[serial]
+#[test]
+fn bind_captured_pattern_composes_with_tuple_struct() {
+    // `whole @ Shape::Circle(r)` should bind both the alias and the
+    // tuple-struct payload when the overall pattern's type is already known
+    // (propagated from the let's explicit annotation).
+    let source = dedent(
+        "
+        enum Shape {
+            Circle(f64),
+        }
+
+        fn main() {
+            let whole @ Shape::Circle(r): Shape = Shape::Circle(2.0);
+            drop(whole);
+            drop(r);
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        assert_bind_symbol(cc, "whole", BindExpect::new(SymKind::Variable).with_type_of("Shape"));
+        assert_bind_symbol(cc, "r", BindExpect::new(SymKind::Variable).with_type_of("f64"));
+    });
+}
+
+#[serial]
+#[test]
+fn bind_for_loop_pattern_over_array() {
+    // `nums: [i32; 3]` lets the loop pattern bind to the array's element
+    // type, resolved through the same `CompositeType` symbol array type
+    // annotations already synthesize.
+    let source = dedent(
+        "
+        fn main() {
+            let nums: [i32; 3] = [1, 2, 3];
+            for n in nums {
+                drop(n);
+            }
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        assert_bind_symbol(cc, "n", BindExpect::new(SymKind::Variable).with_type_of("i32"));
+    });
+}
+
+#[serial]
+#[test]
+fn bind_closure_parameter_and_body_reference() {
+    // A typed closure parameter reuses the function-parameter `parameter`
+    // node, and the closure's own scope (pushed during bind) lets the body
+    // resolve a reference back to that parameter.
+    let source = dedent(
+        "
+        fn main() {
+            let add = |b: u64| {
+                let c = b;
+                drop(c);
+            };
+            drop(add);
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        assert_bind_symbol(cc, "b", BindExpect::new(SymKind::Variable).with_type_of("u64"));
+        assert_bind_symbol(cc, "c", BindExpect::new(SymKind::Variable).with_type_of("u64"));
+    });
+}
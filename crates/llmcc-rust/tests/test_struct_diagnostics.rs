@@ -0,0 +1,155 @@
+mod common;
+
+use common::with_compiled_unit;
+use llmcc_core::context::CompileUnit;
+use llmcc_core::ir::HirNode;
+use llmcc_resolver::BinderScopes;
+use llmcc_rust::pattern::{check_struct_expression_fields, check_struct_pattern_fields};
+use llmcc_rust::token::LangRust;
+use serial_test::serial;
+use textwrap::dedent;
+
+// ==============================================================================
+// Unit tests for pattern.rs - check_struct_pattern_fields / check_struct_expression_fields
+// ==============================================================================
+
+fn find_kind<'tcx>(unit: &CompileUnit<'tcx>, node: &HirNode<'tcx>, kind_id: u16) -> Option<HirNode<'tcx>> {
+    if node.kind_id() == kind_id {
+        return Some(*node);
+    }
+    for child in node.children(unit) {
+        if let Some(found) = find_kind(unit, &child, kind_id) {
+            return Some(found);
+        }
+    }
+    None
+}
+
+fn check_first_struct_pattern<'tcx>(cc: &'tcx llmcc_core::CompileCtxt<'tcx>) -> Option<Vec<String>> {
+    let unit = cc.compile_unit(0);
+    let root = unit.hir_node(unit.file_root_id().expect("file root"));
+    let node = find_kind(&unit, &root, LangRust::struct_pattern).expect("struct pattern");
+    check_struct_pattern_fields(&unit, &node).map(|d| d.missing)
+}
+
+fn check_first_struct_expression<'tcx>(cc: &'tcx llmcc_core::CompileCtxt<'tcx>) -> Option<Vec<String>> {
+    let unit = cc.compile_unit(0);
+    let root = unit.hir_node(unit.file_root_id().expect("file root"));
+    let node = find_kind(&unit, &root, LangRust::struct_expression).expect("struct expression");
+    let globals = cc.create_globals();
+    let scopes = BinderScopes::new(unit, globals);
+    check_struct_expression_fields(&unit, &scopes, &node).map(|d| d.missing)
+}
+
+#[serial]
+#[test]
+fn struct_pattern_missing_field_is_reported() {
+    let source = dedent(
+        "
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+
+        fn destruct(p: Point) {
+            let Point { x } = p;
+            drop(x);
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        assert_eq!(check_first_struct_pattern(cc), Some(vec!["y".to_string()]));
+    });
+}
+
+#[serial]
+#[test]
+fn struct_pattern_with_all_fields_reports_nothing() {
+    let source = dedent(
+        "
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+
+        fn destruct(p: Point) {
+            let Point { x, y } = p;
+            drop(x);
+            drop(y);
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        assert_eq!(check_first_struct_pattern(cc), None);
+    });
+}
+
+#[serial]
+#[test]
+fn struct_pattern_with_rest_reports_nothing() {
+    let source = dedent(
+        "
+        struct Config {
+            name: i32,
+            value: i32,
+            enabled: bool,
+        }
+
+        fn destruct(c: Config) {
+            let Config { name, .. } = c;
+            drop(name);
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        assert_eq!(check_first_struct_pattern(cc), None);
+    });
+}
+
+#[serial]
+#[test]
+fn struct_expression_missing_field_is_reported() {
+    let source = dedent(
+        "
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+
+        fn main() {
+            let p = Point { x: 1 };
+            drop(p);
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        assert_eq!(check_first_struct_expression(cc), Some(vec!["y".to_string()]));
+    });
+}
+
+#[serial]
+#[test]
+fn struct_expression_with_base_spread_reports_nothing() {
+    let source = dedent(
+        "
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+
+        fn main() {
+            let base = Point { x: 1, y: 2 };
+            let moved = Point { x: 3, ..base };
+            drop(moved);
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        assert_eq!(check_first_struct_expression(cc), None);
+    });
+}
@@ -454,6 +454,24 @@ fn range_variants_infer_i32() {
     });
 }
 
+#[serial]
+#[test]
+fn range_full_and_range_to_inclusive_infer_i32() {
+    let source = dedent(
+        "
+        fn main() {
+            let everything = ..;
+            let upto_inclusive = ..=5;
+        }
+        ",
+    );
+
+    with_compiled_unit(&[&source], |cc| {
+        assert_infer_type(cc, "everything", ("i32", SymKind::Primitive));
+        assert_infer_type(cc, "upto_inclusive", ("i32", SymKind::Primitive));
+    });
+}
+
 #[serial]
 #[test]
 fn composite_type_annotations_resolve_element_types() {
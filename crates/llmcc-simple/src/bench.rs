@@ -0,0 +1,123 @@
+//! Structured benchmark reporting for the IR-builder benches.
+//!
+//! The `bench_ir_build_*` tests previously only `println!`ed human text,
+//! which can't be diffed across machines or runs. `BenchReport` captures the
+//! same numbers as structured data plus enough host metadata (core counts, a
+//! CPU speed probe) that `nodes/ms` can be normalized across hardware.
+
+use serde::Serialize;
+use std::io::Write;
+use std::time::Instant;
+
+/// One benchmark run: corpus shape, timings, and the resulting node count.
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchReport {
+    pub config: String,
+    pub files: usize,
+    pub lines: usize,
+    pub nodes: usize,
+    pub parse_us: u128,
+    pub build_us: u128,
+    pub host: HostInfo,
+}
+
+/// Host metadata captured alongside a `BenchReport` so results from
+/// different machines can be compared on equal footing.
+#[derive(Debug, Clone, Serialize)]
+pub struct HostInfo {
+    pub logical_cpus: usize,
+    pub physical_cpus: usize,
+    pub cpu_speed_mibs: f64,
+}
+
+impl HostInfo {
+    /// Probe the current host: core counts plus a fixed-iteration busy loop
+    /// measured in MiB/s, so throughput numbers can be normalized across
+    /// hardware instead of read as raw wall-clock time.
+    pub fn probe() -> Self {
+        Self {
+            logical_cpus: logical_cpu_count(),
+            physical_cpus: physical_cpu_count(),
+            cpu_speed_mibs: cpu_speed_probe(),
+        }
+    }
+}
+
+impl BenchReport {
+    pub fn new(
+        config: impl Into<String>,
+        files: usize,
+        lines: usize,
+        nodes: usize,
+        parse_us: u128,
+        build_us: u128,
+    ) -> Self {
+        Self {
+            config: config.into(),
+            files,
+            lines,
+            nodes,
+            parse_us,
+            build_us,
+            host: HostInfo::probe(),
+        }
+    }
+
+    /// Nodes built per millisecond of build time.
+    pub fn nodes_per_ms(&self) -> f64 {
+        self.nodes as f64 / (self.build_us as f64 / 1000.0)
+    }
+
+    /// Append this report as a JSON line to the path named by the
+    /// `LLMCC_BENCH_REPORT` env var. No-op if the var isn't set, so benches
+    /// stay silent by default and only emit machine-readable output when a
+    /// regression harness opts in.
+    pub fn emit(&self) {
+        let Ok(path) = std::env::var("LLMCC_BENCH_REPORT") else {
+            return;
+        };
+        let Ok(line) = serde_json::to_string(self) else {
+            return;
+        };
+        if let Ok(mut file) = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+        {
+            let _ = writeln!(file, "{line}");
+        }
+    }
+}
+
+fn logical_cpu_count() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
+
+/// Approximate physical core count without an external crate dependency:
+/// halve the logical count (SMT is the common case), rounding up.
+fn physical_cpu_count() -> usize {
+    let logical = logical_cpu_count();
+    if logical > 1 {
+        logical.div_ceil(2)
+    } else {
+        1
+    }
+}
+
+/// Fixed-iteration busy loop, measured in MiB/s, as lightweight
+/// hardware-benchmarking harnesses do: not a real workload, just a figure
+/// that scales with this machine's raw integer throughput.
+fn cpu_speed_probe() -> f64 {
+    const ITERATIONS: u64 = 50_000_000;
+    let start = Instant::now();
+    let mut acc: u64 = 0xDEAD_BEEF;
+    for i in 0..ITERATIONS {
+        acc = acc.wrapping_mul(2_862_933_555_777_941_757).wrapping_add(i);
+    }
+    std::hint::black_box(acc);
+    let elapsed = start.elapsed().as_secs_f64().max(1e-9);
+    let mib_processed = (ITERATIONS * 8) as f64 / (1024.0 * 1024.0);
+    mib_processed / elapsed
+}
@@ -7,6 +7,8 @@
 #[macro_use]
 extern crate llmcc_core;
 
+pub mod bench;
+
 use llmcc_core::graph_builder::BlockKind;
 use llmcc_core::ir::HirKind;
 use llmcc_core::lang_def::{LanguageTraitExt, ParseNode, ParseTree};
@@ -38,6 +40,8 @@ llmcc_core::define_lang!(
 pub struct SimpleParseNode {
     pub kind_id: u16,
     pub text: String,
+    pub start_byte: usize,
+    pub end_byte: usize,
     pub children: Vec<SimpleParseNode>,
 }
 
@@ -47,11 +51,11 @@ impl ParseNode for SimpleParseNode {
     }
 
     fn start_byte(&self) -> usize {
-        0 // Simplified for example
+        self.start_byte
     }
 
     fn end_byte(&self) -> usize {
-        self.text.len()
+        self.end_byte
     }
 
     fn child_count(&self) -> usize {
@@ -68,6 +72,10 @@ impl ParseNode for SimpleParseNode {
         None // Simplified for example
     }
 
+    fn boxed(&self) -> Box<dyn ParseNode + '_> {
+        Box::new(self.clone())
+    }
+
     fn debug_info(&self) -> String {
         format!(
             "SimpleParseNode(kind_id: {}, text: {})",
@@ -121,19 +129,36 @@ mod simple_parser {
     pub fn parse(source: &[u8]) -> Option<SimpleParseNode> {
         let text = std::str::from_utf8(source).ok()?;
         let mut children = Vec::new();
+        let mut offset = 0usize;
 
         // Parse "fn" keyword lines as functions
-        for line in text.lines() {
+        for raw_line in text.split_inclusive('\n') {
+            let line = raw_line.strip_suffix('\n').unwrap_or(raw_line);
+            let line_start = offset;
+            offset += raw_line.len();
+
+            let leading_ws = line.len() - line.trim_start().len();
             let trimmed = line.trim();
+            let trimmed_start = line_start + leading_ws;
+            let trimmed_end = trimmed_start + trimmed.len();
+
             if trimmed.starts_with("fn ") {
                 let func_name = trimmed.split('(').next().unwrap_or("").replace("fn ", "");
+                let name_start = trimmed
+                    .find(func_name.as_str())
+                    .map_or(trimmed_start, |i| trimmed_start + i);
+                let name_end = name_start + func_name.len();
 
                 children.push(SimpleParseNode {
                     kind_id: LangSimple::function,
                     text: trimmed.to_string(),
+                    start_byte: trimmed_start,
+                    end_byte: trimmed_end,
                     children: vec![SimpleParseNode {
                         kind_id: LangSimple::identifier,
                         text: func_name,
+                        start_byte: name_start,
+                        end_byte: name_end,
                         children: Vec::new(),
                     }],
                 });
@@ -142,6 +167,8 @@ mod simple_parser {
                 children.push(SimpleParseNode {
                     kind_id: LangSimple::statement,
                     text: trimmed.to_string(),
+                    start_byte: trimmed_start,
+                    end_byte: trimmed_end,
                     children: Vec::new(),
                 });
             }
@@ -150,6 +177,8 @@ mod simple_parser {
         Some(SimpleParseNode {
             kind_id: LangSimple::module,
             text: text.to_string(),
+            start_byte: 0,
+            end_byte: text.len(),
             children,
         })
     }
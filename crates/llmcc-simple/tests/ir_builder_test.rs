@@ -10,6 +10,7 @@
 use llmcc_core::context::CompileCtxt;
 use llmcc_core::ir_builder::{IrBuildOption, build_llmcc_ir};
 use llmcc_simple::LangSimple;
+use llmcc_simple::bench::BenchReport;
 use std::collections::HashSet;
 use std::time::Instant;
 
@@ -340,6 +341,16 @@ fn bench_ir_build_100_files_100_lines() {
         hir_nodes,
         hir_nodes as f64 / build_time.as_secs_f64() / 1000.0
     );
+
+    BenchReport::new(
+        "100_files_100_lines",
+        NUM_FILES,
+        NUM_FILES * LINES_PER_FILE,
+        hir_nodes,
+        parse_time.as_micros(),
+        build_time.as_micros(),
+    )
+    .emit();
 }
 
 /// Benchmark: 500 files × 1000 lines (500k total lines)
@@ -382,6 +393,16 @@ fn bench_ir_build_500_files_1000_lines() {
         hir_nodes,
         hir_nodes as f64 / build_time.as_secs_f64() / 1000.0
     );
+
+    BenchReport::new(
+        "500_files_1000_lines",
+        NUM_FILES,
+        NUM_FILES * LINES_PER_FILE,
+        hir_nodes,
+        parse_time.as_micros(),
+        build_time.as_micros(),
+    )
+    .emit();
 }
 
 /// Benchmark: 1000 files × 10k lines (production scale - ignored by default)
@@ -432,6 +453,16 @@ fn bench_ir_build_1000_files_10k_lines() {
         hir_nodes,
         hir_nodes as f64 / build_time.as_secs_f64()
     );
+
+    BenchReport::new(
+        "1000_files_10k_lines",
+        NUM_FILES,
+        total_lines,
+        hir_nodes,
+        parse_time.as_micros(),
+        build_time.as_micros(),
+    )
+    .emit();
 }
 
 /// Benchmark: Scaling analysis across different file/line distributions
@@ -467,6 +498,16 @@ fn bench_ir_build_scaling_analysis() {
             hir_nodes,
             total_time.as_secs_f64()
         );
+
+        BenchReport::new(
+            format!("scaling_{num_files}_files_{lines_per_file}_lines"),
+            num_files,
+            num_files * lines_per_file,
+            hir_nodes,
+            parse_time.as_micros(),
+            build_time.as_micros(),
+        )
+        .emit();
     }
 
     // Verify sub-quadratic scaling
@@ -3,13 +3,15 @@ use std::fs;
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result, anyhow};
+use llmcc_cli::options::DumpStages;
 use llmcc_core::ProjectGraph;
 use llmcc_core::block::reset_block_id_counter;
 use llmcc_core::context::{CompileCtxt, CompileUnit};
 use llmcc_core::graph_builder::{BlockId, BlockRelation, GraphBuildOption, build_llmcc_graph};
 use llmcc_core::ir_builder::{IrBuildOption, build_llmcc_ir};
 use llmcc_core::lang_def::LanguageTraitImpl;
-use llmcc_core::symbol::reset_symbol_id_counter;
+use llmcc_core::printer::print_llmcc_ir;
+use llmcc_core::symbol::{SymKind, reset_symbol_id_counter};
 
 use llmcc_resolver::{ResolverOption, bind_symbols_with, collect_symbols_with};
 use llmcc_rust::LangRust;
@@ -817,18 +819,29 @@ fn collect_pipeline<L>(
 where
     L: LanguageTraitImpl,
 {
+    // Stage dumps default off here and are picked up from `LLMCC_DUMP`
+    // (e.g. `LLMCC_DUMP=collect,bind,graph,unresolved`) so a flaky corpus
+    // case can be debugged without threading CLI flags through the runner.
+    let dump_stages = DumpStages::resolve(&Default::default());
+
     let files = discover_language_files::<L>(project_root)?;
     let cc = CompileCtxt::from_files::<L>(&files).unwrap();
     build_llmcc_ir::<L>(&cc, IrBuildOption).unwrap();
 
     // Use new unified API for symbol collection with optional IR printing
     let resolver_option = ResolverOption::default()
-        .with_print_ir(true)
+        .with_print_ir(dump_stages.collect)
         .with_sequential(true);
     let globals = collect_symbols_with::<L>(&cc, &resolver_option);
 
     // Bind symbols using new unified API
     bind_symbols_with::<L>(&cc, globals, &resolver_option);
+    if dump_stages.bind {
+        for index in 0..files.len() {
+            let _ = print_llmcc_ir(cc.compile_unit(index));
+        }
+    }
+
     let mut project_graph = if build_graph || build_block_reports || build_block_graph {
         Some(ProjectGraph::new(&cc))
     } else {
@@ -838,10 +851,19 @@ where
         let unit_graphs =
             build_llmcc_graph::<L>(&cc, GraphBuildOption::new().with_sequential(true)).unwrap();
         project.add_children(unit_graphs);
+        if dump_stages.graph {
+            for unit_graph in project.units() {
+                let unit = cc.compile_unit(unit_graph.unit_index());
+                let _ = llmcc_core::printer::print_llmcc_graph(unit_graph.root(), unit);
+            }
+        }
     }
     let (graph_dot, block_list, block_deps, block_graph) = if let Some(mut project) = project_graph
     {
         project.link_units();
+        if dump_stages.unresolved {
+            dump_unresolved_symbols(&cc);
+        }
         let graph = if build_graph {
             Some(project.render_design_graph())
         } else {
@@ -885,6 +907,31 @@ where
     })
 }
 
+/// Print the names of any symbols still carrying the `UnresolvedType`
+/// placeholder kind after linking - the queue `assert_no_unresolved`-style
+/// checks want emptied out.
+fn dump_unresolved_symbols(cc: &CompileCtxt) {
+    let mut names = Vec::new();
+    cc.for_each_symbol(|_, symbol| {
+        if symbol.kind() == SymKind::UnresolvedType {
+            let name = cc
+                .interner
+                .resolve_owned(symbol.name)
+                .unwrap_or_else(|| "<unknown>".to_string());
+            names.push(name);
+        }
+    });
+
+    if names.is_empty() {
+        println!("dump(unresolved): none");
+    } else {
+        println!("dump(unresolved): {} symbol(s)", names.len());
+        for name in names {
+            println!("  - {name}");
+        }
+    }
+}
+
 fn discover_language_files<L: LanguageTraitImpl>(root: &Path) -> Result<Vec<String>> {
     let supported = L::supported_extensions();
     let mut files = Vec::new();
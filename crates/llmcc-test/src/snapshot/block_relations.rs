@@ -88,6 +88,46 @@ impl Snapshot for BlockRelationsSnapshot {
                     relations.push((BlockRelation::MethodOf, labels));
                 }
 
+                // Check for HasAssocType relation (impl/trait -> associated type blocks)
+                let has_assoc_type = related_map.get_related(block_id, BlockRelation::HasAssocType);
+                if !has_assoc_type.is_empty() {
+                    let labels: Vec<String> = has_assoc_type
+                        .iter()
+                        .map(|id| format!("u{}:{}", unit_index, id.as_u32()))
+                        .collect();
+                    relations.push((BlockRelation::HasAssocType, labels));
+                }
+
+                // Check for AssocTypeOf relation (associated type <- impl/trait)
+                let assoc_type_of = related_map.get_related(block_id, BlockRelation::AssocTypeOf);
+                if !assoc_type_of.is_empty() {
+                    let labels: Vec<String> = assoc_type_of
+                        .iter()
+                        .map(|id| format!("u{}:{}", unit_index, id.as_u32()))
+                        .collect();
+                    relations.push((BlockRelation::AssocTypeOf, labels));
+                }
+
+                // Check for HasAssocConst relation (impl/trait -> associated const blocks)
+                let has_assoc_const = related_map.get_related(block_id, BlockRelation::HasAssocConst);
+                if !has_assoc_const.is_empty() {
+                    let labels: Vec<String> = has_assoc_const
+                        .iter()
+                        .map(|id| format!("u{}:{}", unit_index, id.as_u32()))
+                        .collect();
+                    relations.push((BlockRelation::HasAssocConst, labels));
+                }
+
+                // Check for AssocConstOf relation (associated const <- impl/trait)
+                let assoc_const_of = related_map.get_related(block_id, BlockRelation::AssocConstOf);
+                if !assoc_const_of.is_empty() {
+                    let labels: Vec<String> = assoc_const_of
+                        .iter()
+                        .map(|id| format!("u{}:{}", unit_index, id.as_u32()))
+                        .collect();
+                    relations.push((BlockRelation::AssocConstOf, labels));
+                }
+
                 // Check for Contains relation (structural parent -> child)
                 let contains = related_map.get_related(block_id, BlockRelation::Contains);
                 if !contains.is_empty() {
@@ -128,6 +168,46 @@ impl Snapshot for BlockRelationsSnapshot {
                     relations.push((BlockRelation::CalledBy, labels));
                 }
 
+                // Check for Implements relation (impl -> trait it implements)
+                let implements = related_map.get_related(block_id, BlockRelation::Implements);
+                if !implements.is_empty() {
+                    let labels: Vec<String> = implements
+                        .iter()
+                        .map(|id| format!("u{}:{}", unit_index, id.as_u32()))
+                        .collect();
+                    relations.push((BlockRelation::Implements, labels));
+                }
+
+                // Check for ImplementedBy relation (trait <- implementing impls)
+                let implemented_by = related_map.get_related(block_id, BlockRelation::ImplementedBy);
+                if !implemented_by.is_empty() {
+                    let labels: Vec<String> = implemented_by
+                        .iter()
+                        .map(|id| format!("u{}:{}", unit_index, id.as_u32()))
+                        .collect();
+                    relations.push((BlockRelation::ImplementedBy, labels));
+                }
+
+                // Check for Overrides relation (impl method -> trait default/decl method)
+                let overrides = related_map.get_related(block_id, BlockRelation::Overrides);
+                if !overrides.is_empty() {
+                    let labels: Vec<String> = overrides
+                        .iter()
+                        .map(|id| format!("u{}:{}", unit_index, id.as_u32()))
+                        .collect();
+                    relations.push((BlockRelation::Overrides, labels));
+                }
+
+                // Check for DynCall relation (call -> candidate methods across implementors)
+                let dyn_call = related_map.get_related(block_id, BlockRelation::DynCall);
+                if !dyn_call.is_empty() {
+                    let labels: Vec<String> = dyn_call
+                        .iter()
+                        .map(|id| format!("u{}:{}", unit_index, id.as_u32()))
+                        .collect();
+                    relations.push((BlockRelation::DynCall, labels));
+                }
+
                 // Only include blocks that have relations
                 if !relations.is_empty() {
                     entries.push(RelationEntry {
@@ -171,6 +251,70 @@ impl Snapshot for BlockRelationsSnapshot {
     }
 
     fn normalize(text: &str) -> String {
+        Self::normalize_render(text)
+    }
+}
+
+/// Edge line style keyed by relation type: dashed for call edges (including
+/// dispatched-through-a-trait `DynCall`), dotted for `ImplFor`, `Implements`
+/// and `Overrides`, and solid for everything else, including the inverse
+/// `HasImpl`/`ImplementedBy` edges.
+fn edge_style(relation: BlockRelation) -> &'static str {
+    match relation {
+        BlockRelation::Contains
+        | BlockRelation::ContainedBy
+        | BlockRelation::HasMethod
+        | BlockRelation::MethodOf
+        | BlockRelation::HasImpl
+        | BlockRelation::ImplementedBy
+        | BlockRelation::HasAssocType
+        | BlockRelation::AssocTypeOf
+        | BlockRelation::HasAssocConst
+        | BlockRelation::AssocConstOf => "solid",
+        BlockRelation::Calls | BlockRelation::CalledBy | BlockRelation::DynCall => "dashed",
+        BlockRelation::ImplFor | BlockRelation::Implements | BlockRelation::Overrides => "dotted",
+        _ => "solid",
+    }
+}
+
+impl BlockRelationsSnapshot {
+    /// Render the captured relations as a Graphviz DOT digraph: one node per
+    /// [`RelationEntry`] labeled `label | kind | name`, and one edge per
+    /// `(BlockRelation, target)` pair, styled by relation type so
+    /// `Contains`/`HasMethod` (solid), `Calls` (dashed), and `ImplFor`
+    /// (dotted) edges are visually distinguishable when piped into
+    /// `dot`/`graphviz`.
+    pub fn render_dot(&self) -> String {
+        let mut buf = String::new();
+        let _ = writeln!(buf, "digraph block_relations {{");
+        let _ = writeln!(buf, "  rankdir=LR;");
+        let _ = writeln!(buf, "  node [shape=box];");
+        buf.push('\n');
+
+        for entry in &self.entries {
+            let label = format!("{} | {} | {}", entry.label, entry.kind, entry.name);
+            let _ = writeln!(buf, "  \"{}\" [label=\"{}\"];", entry.label, label);
+        }
+        buf.push('\n');
+
+        for entry in &self.entries {
+            for (relation, targets) in &entry.relations {
+                let style = edge_style(*relation);
+                for target in targets {
+                    let _ = writeln!(
+                        buf,
+                        "  \"{}\" -> \"{}\" [label=\"{:?}\", style={}];",
+                        entry.label, target, relation, style
+                    );
+                }
+            }
+        }
+
+        buf.push_str("}\n");
+        buf
+    }
+
+    fn normalize_render(text: &str) -> String {
         let canonical = text
             .replace("\r\n", "\n")
             .trim_end_matches('\n')
@@ -224,4 +368,108 @@ u0:3 | Struct | Foo
         // u0:3 should come before u0:5
         assert!(normalized.find("u0:3").unwrap() < normalized.find("u0:5").unwrap());
     }
+
+    #[test]
+    fn test_render_dot_styles_edges_by_relation() {
+        let snapshot = BlockRelationsSnapshot {
+            entries: vec![
+                RelationEntry {
+                    label: "u0:5".to_string(),
+                    kind: "Impl".to_string(),
+                    name: String::new(),
+                    relations: vec![(BlockRelation::ImplFor, vec!["u0:3".to_string()])],
+                },
+                RelationEntry {
+                    label: "u0:3".to_string(),
+                    kind: "Struct".to_string(),
+                    name: "Foo".to_string(),
+                    relations: vec![(BlockRelation::Calls, vec!["u0:7".to_string()])],
+                },
+            ],
+        };
+
+        let dot = snapshot.render_dot();
+
+        assert!(dot.starts_with("digraph block_relations {\n"));
+        assert!(dot.contains("\"u0:5\" [label=\"u0:5 | Impl | \"];"));
+        assert!(dot.contains("\"u0:3\" [label=\"u0:3 | Struct | Foo\"];"));
+        assert!(dot.contains("\"u0:5\" -> \"u0:3\" [label=\"ImplFor\", style=dotted];"));
+        assert!(dot.contains("\"u0:3\" -> \"u0:7\" [label=\"Calls\", style=dashed];"));
+        assert!(dot.trim_end().ends_with('}'));
+    }
+
+    #[test]
+    fn test_render_dot_styles_trait_and_dyn_call_edges() {
+        let snapshot = BlockRelationsSnapshot {
+            entries: vec![
+                RelationEntry {
+                    label: "u0:5".to_string(),
+                    kind: "Impl".to_string(),
+                    name: String::new(),
+                    relations: vec![(BlockRelation::Implements, vec!["u0:2".to_string()])],
+                },
+                RelationEntry {
+                    label: "u0:2".to_string(),
+                    kind: "Trait".to_string(),
+                    name: "Shape".to_string(),
+                    relations: vec![(BlockRelation::ImplementedBy, vec!["u0:5".to_string()])],
+                },
+                RelationEntry {
+                    label: "u0:6".to_string(),
+                    kind: "Fn".to_string(),
+                    name: "area".to_string(),
+                    relations: vec![(BlockRelation::Overrides, vec!["u0:3".to_string()])],
+                },
+                RelationEntry {
+                    label: "u0:9".to_string(),
+                    kind: "Call".to_string(),
+                    name: String::new(),
+                    relations: vec![(BlockRelation::DynCall, vec!["u0:6".to_string()])],
+                },
+            ],
+        };
+
+        let dot = snapshot.render_dot();
+
+        assert!(dot.contains("\"u0:5\" -> \"u0:2\" [label=\"Implements\", style=dotted];"));
+        assert!(dot.contains("\"u0:2\" -> \"u0:5\" [label=\"ImplementedBy\", style=solid];"));
+        assert!(dot.contains("\"u0:6\" -> \"u0:3\" [label=\"Overrides\", style=dotted];"));
+        assert!(dot.contains("\"u0:9\" -> \"u0:6\" [label=\"DynCall\", style=dashed];"));
+    }
+
+    #[test]
+    fn test_render_dot_styles_assoc_item_edges() {
+        let snapshot = BlockRelationsSnapshot {
+            entries: vec![
+                RelationEntry {
+                    label: "u0:5".to_string(),
+                    kind: "Impl".to_string(),
+                    name: String::new(),
+                    relations: vec![
+                        (BlockRelation::HasAssocType, vec!["u0:8".to_string()]),
+                        (BlockRelation::HasAssocConst, vec!["u0:9".to_string()]),
+                    ],
+                },
+                RelationEntry {
+                    label: "u0:8".to_string(),
+                    kind: "Alias".to_string(),
+                    name: "Item".to_string(),
+                    relations: vec![(BlockRelation::AssocTypeOf, vec!["u0:5".to_string()])],
+                },
+                RelationEntry {
+                    label: "u0:9".to_string(),
+                    kind: "Const".to_string(),
+                    name: "MAX".to_string(),
+                    relations: vec![(BlockRelation::AssocConstOf, vec!["u0:5".to_string()])],
+                },
+            ],
+        };
+
+        let dot = snapshot.render_dot();
+
+        assert!(dot.contains("\"u0:5\" -> \"u0:8\" [label=\"HasAssocType\", style=solid];"));
+        assert!(dot.contains("\"u0:5\" -> \"u0:9\" [label=\"HasAssocConst\", style=solid];"));
+        assert!(dot.contains("\"u0:8\" -> \"u0:5\" [label=\"AssocTypeOf\", style=solid];"));
+        assert!(dot.contains("\"u0:9\" -> \"u0:5\" [label=\"AssocConstOf\", style=solid];"));
+    }
 }
@@ -0,0 +1,257 @@
+//! Call-graph snapshot: transitive reachability and recursion-cycle (SCC)
+//! detection layered on top of the `Calls` relation already captured by
+//! `connect_blocks()`.
+
+use super::{Snapshot, SnapshotContext};
+use llmcc_core::block::{BlockId, BlockKind, BlockRelation};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+
+/// Snapshot of the function call graph: per-function transitive callee
+/// counts, plus any strongly-connected components of size > 1 (mutual
+/// recursion) or a function that calls itself.
+#[derive(Clone)]
+pub struct CallGraphSnapshot {
+    entries: Vec<FuncEntry>,
+    /// Each inner vec is one recursive cycle, as a sorted list of labels.
+    cycles: Vec<Vec<String>>,
+}
+
+#[derive(Clone)]
+struct FuncEntry {
+    label: String,
+    name: String,
+    transitive_callees: usize,
+}
+
+impl Snapshot for CallGraphSnapshot {
+    fn capture(ctx: SnapshotContext<'_>) -> Self {
+        let related_map = &ctx.cc.related_map;
+        let edges = |id: BlockId| related_map.get_related(id, BlockRelation::Calls);
+
+        let mut funcs = Vec::new();
+        for unit_index in 0..ctx.cc.files.len() {
+            for (name_opt, kind, block_id) in ctx.cc.find_blocks_in_unit(unit_index) {
+                if kind == BlockKind::Func {
+                    let label = format!("u{}:{}", unit_index, block_id.as_u32());
+                    funcs.push((block_id, label, name_opt.unwrap_or_default()));
+                }
+            }
+        }
+
+        let labels: HashMap<BlockId, String> = funcs
+            .iter()
+            .map(|(id, label, _)| (*id, label.clone()))
+            .collect();
+
+        let mut entries = Vec::with_capacity(funcs.len());
+        for (block_id, label, name) in &funcs {
+            let transitive = transitive_reachable(*block_id, &edges);
+            entries.push(FuncEntry {
+                label: label.clone(),
+                name: name.clone(),
+                transitive_callees: transitive.len(),
+            });
+        }
+        entries.sort_by(|a, b| a.label.cmp(&b.label));
+
+        let node_ids: Vec<BlockId> = funcs.iter().map(|(id, _, _)| *id).collect();
+        let mut cycles: Vec<Vec<String>> = tarjan_scc(&node_ids, &edges)
+            .into_iter()
+            .filter(|scc| scc.len() > 1 || edges(scc[0]).contains(&scc[0]))
+            .map(|scc| {
+                let mut names: Vec<String> = scc
+                    .iter()
+                    .map(|id| labels.get(id).cloned().unwrap_or_default())
+                    .collect();
+                names.sort();
+                names
+            })
+            .collect();
+        cycles.sort();
+
+        Self { entries, cycles }
+    }
+
+    fn render(&self) -> String {
+        if self.entries.is_empty() {
+            return "none\n".to_string();
+        }
+
+        let mut buf = String::new();
+        for entry in &self.entries {
+            let _ = writeln!(
+                buf,
+                "{} | {} | transitive={}",
+                entry.label, entry.name, entry.transitive_callees
+            );
+        }
+
+        if !self.cycles.is_empty() {
+            buf.push_str("cycles:\n");
+            for cycle in &self.cycles {
+                let _ = writeln!(buf, "  [{}]", cycle.join(", "));
+            }
+        }
+
+        buf
+    }
+
+    fn normalize(text: &str) -> String {
+        text.replace("\r\n", "\n").trim_end_matches('\n').to_string()
+    }
+}
+
+/// DFS over `Calls` edges from `start`, returning the transitive callee set
+/// (excludes `start` itself unless reached again via a cycle).
+fn transitive_reachable(start: BlockId, edges: &impl Fn(BlockId) -> Vec<BlockId>) -> HashSet<BlockId> {
+    let mut seen = HashSet::new();
+    let mut stack = edges(start);
+    while let Some(id) = stack.pop() {
+        if seen.insert(id) {
+            stack.extend(edges(id));
+        }
+    }
+    seen
+}
+
+/// Tarjan's SCC algorithm over `Calls` edges: per-node `index`/`lowlink`
+/// with an explicit on-stack set, popping one SCC whenever a node's
+/// `lowlink` comes back equal to its own `index`.
+fn tarjan_scc(nodes: &[BlockId], edges: &impl Fn(BlockId) -> Vec<BlockId>) -> Vec<Vec<BlockId>> {
+    struct State {
+        index: HashMap<BlockId, usize>,
+        lowlink: HashMap<BlockId, usize>,
+        on_stack: HashSet<BlockId>,
+        stack: Vec<BlockId>,
+        counter: usize,
+        sccs: Vec<Vec<BlockId>>,
+    }
+
+    fn strongconnect(node: BlockId, state: &mut State, edges: &impl Fn(BlockId) -> Vec<BlockId>) {
+        state.index.insert(node, state.counter);
+        state.lowlink.insert(node, state.counter);
+        state.counter += 1;
+        state.stack.push(node);
+        state.on_stack.insert(node);
+
+        for succ in edges(node) {
+            if !state.index.contains_key(&succ) {
+                strongconnect(succ, state, edges);
+                let merged = state.lowlink[&node].min(state.lowlink[&succ]);
+                state.lowlink.insert(node, merged);
+            } else if state.on_stack.contains(&succ) {
+                let merged = state.lowlink[&node].min(state.index[&succ]);
+                state.lowlink.insert(node, merged);
+            }
+        }
+
+        if state.lowlink[&node] == state.index[&node] {
+            let mut scc = Vec::new();
+            loop {
+                let popped = state.stack.pop().expect("node pushed before strongconnect returns");
+                state.on_stack.remove(&popped);
+                scc.push(popped);
+                if popped == node {
+                    break;
+                }
+            }
+            state.sccs.push(scc);
+        }
+    }
+
+    let mut state = State {
+        index: HashMap::new(),
+        lowlink: HashMap::new(),
+        on_stack: HashSet::new(),
+        stack: Vec::new(),
+        counter: 0,
+        sccs: Vec::new(),
+    };
+
+    for &node in nodes {
+        if !state.index.contains_key(&node) {
+            strongconnect(node, &mut state, edges);
+        }
+    }
+
+    state.sccs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id(n: u32) -> BlockId {
+        BlockId::new(n)
+    }
+
+    #[test]
+    fn test_transitive_reachable_follows_multiple_hops() {
+        let graph: HashMap<BlockId, Vec<BlockId>> =
+            HashMap::from([(id(1), vec![id(2)]), (id(2), vec![id(3)]), (id(3), vec![])]);
+        let edges = |n: BlockId| graph.get(&n).cloned().unwrap_or_default();
+
+        let reachable = transitive_reachable(id(1), &edges);
+        assert_eq!(reachable, HashSet::from([id(2), id(3)]));
+    }
+
+    #[test]
+    fn test_tarjan_scc_finds_mutual_recursion() {
+        // 1 -> 2 -> 1 (cycle), 2 -> 3 (acyclic tail)
+        let graph: HashMap<BlockId, Vec<BlockId>> =
+            HashMap::from([(id(1), vec![id(2)]), (id(2), vec![id(1), id(3)]), (id(3), vec![])]);
+        let edges = |n: BlockId| graph.get(&n).cloned().unwrap_or_default();
+
+        let sccs = tarjan_scc(&[id(1), id(2), id(3)], &edges);
+        let cyclic: Vec<&Vec<BlockId>> = sccs.iter().filter(|scc| scc.len() > 1).collect();
+
+        assert_eq!(cyclic.len(), 1);
+        let mut members = cyclic[0].clone();
+        members.sort();
+        assert_eq!(members, vec![id(1), id(2)]);
+    }
+
+    #[test]
+    fn test_tarjan_scc_self_edge_is_its_own_singleton_scc() {
+        let graph: HashMap<BlockId, Vec<BlockId>> = HashMap::from([(id(1), vec![id(1)])]);
+        let edges = |n: BlockId| graph.get(&n).cloned().unwrap_or_default();
+
+        let sccs = tarjan_scc(&[id(1)], &edges);
+        assert_eq!(sccs, vec![vec![id(1)]]);
+    }
+
+    #[test]
+    fn test_render_lists_transitive_counts_and_cycles() {
+        let snapshot = CallGraphSnapshot {
+            entries: vec![
+                FuncEntry {
+                    label: "u0:1".to_string(),
+                    name: "main".to_string(),
+                    transitive_callees: 2,
+                },
+                FuncEntry {
+                    label: "u0:2".to_string(),
+                    name: "helper".to_string(),
+                    transitive_callees: 0,
+                },
+            ],
+            cycles: vec![vec!["u0:3".to_string(), "u0:4".to_string()]],
+        };
+
+        let rendered = snapshot.render();
+
+        assert!(rendered.contains("u0:1 | main | transitive=2"));
+        assert!(rendered.contains("u0:2 | helper | transitive=0"));
+        assert!(rendered.contains("cycles:\n  [u0:3, u0:4]"));
+    }
+
+    #[test]
+    fn test_render_empty_is_none() {
+        let snapshot = CallGraphSnapshot {
+            entries: Vec::new(),
+            cycles: Vec::new(),
+        };
+        assert_eq!(snapshot.render(), "none\n");
+    }
+}
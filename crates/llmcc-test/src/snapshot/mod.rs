@@ -5,10 +5,12 @@
 
 mod block_graph;
 mod block_relations;
+mod call_graph;
 mod symbols;
 
 pub use block_graph::BlockGraphSnapshot;
 pub use block_relations::BlockRelationsSnapshot;
+pub use call_graph::CallGraphSnapshot;
 pub use symbols::SymbolsSnapshot;
 
 /// A snapshot that can be captured from compilation context and rendered to text.
@@ -3,6 +3,8 @@
 pub mod config;
 mod node_types;
 
+pub use node_types::{ChildType, FieldSpec, NodeTypes};
+
 use std::fmt::Write;
 use std::path::Path;
 
@@ -13,6 +13,7 @@ use crate::Result;
 #[derive(Debug, Default)]
 pub struct NodeTypes {
     named: HashMap<String, bool>,
+    entries: HashMap<String, NodeTypeEntry>,
 }
 
 impl NodeTypes {
@@ -35,15 +36,71 @@ impl NodeTypes {
             )
         })?;
         let mut named = HashMap::new();
+        let mut by_kind = HashMap::new();
         for entry in entries {
-            named.entry(entry.kind).or_insert(entry.named);
+            named.entry(entry.kind.clone()).or_insert(entry.named);
+            by_kind.entry(entry.kind.clone()).or_insert(entry);
         }
-        Ok(Self { named })
+        Ok(Self {
+            named,
+            entries: by_kind,
+        })
     }
 
     pub fn is_named(&self, name: &str) -> Option<bool> {
         self.named.get(name).copied()
     }
+
+    /// Look up the field spec for `field_name` on the node kind `kind`.
+    pub fn fields(&self, kind: &str, field_name: &str) -> Option<&FieldSpec> {
+        self.entries.get(kind)?.fields.get(field_name)
+    }
+
+    /// The anonymous-children spec for `kind`, if it accepts unnamed children.
+    pub fn child_types(&self, kind: &str) -> Option<&FieldSpec> {
+        self.entries.get(kind)?.children.as_ref()
+    }
+
+    /// The concrete subtype kinds a supertype node (e.g. `_expression`) can stand for.
+    pub fn subtypes(&self, supertype: &str) -> &[ChildType] {
+        self.entries
+            .get(supertype)
+            .map(|e| e.subtypes.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Flatten a supertype into the concrete named kinds it can resolve to,
+    /// recursing through any subtypes that are themselves supertypes.
+    pub fn resolve_supertype(&self, name: &str) -> Vec<&str> {
+        let mut out = Vec::new();
+        let mut seen = std::collections::HashSet::new();
+        self.resolve_supertype_into(name, &mut out, &mut seen);
+        out
+    }
+
+    fn resolve_supertype_into<'a>(
+        &'a self,
+        name: &str,
+        out: &mut Vec<&'a str>,
+        seen: &mut std::collections::HashSet<String>,
+    ) {
+        if !seen.insert(name.to_string()) {
+            return;
+        }
+        let subtypes = self.subtypes(name);
+        if subtypes.is_empty() {
+            out.push(
+                self.entries
+                    .get(name)
+                    .map(|e| e.kind.as_str())
+                    .unwrap_or(name),
+            );
+            return;
+        }
+        for sub in subtypes {
+            self.resolve_supertype_into(&sub.kind, out, seen);
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -52,4 +109,31 @@ struct NodeTypeEntry {
     kind: String,
     #[serde(default)]
     named: bool,
+    #[serde(default)]
+    fields: HashMap<String, FieldSpec>,
+    #[serde(default)]
+    children: Option<FieldSpec>,
+    #[serde(default)]
+    subtypes: Vec<ChildType>,
+}
+
+/// The allowed child types for a field or the anonymous `children` slot,
+/// along with tree-sitter's `multiple`/`required` cardinality flags.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct FieldSpec {
+    #[serde(default)]
+    pub multiple: bool,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default)]
+    pub types: Vec<ChildType>,
+}
+
+/// One entry of a field's `types` array: a child kind and whether it's named.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChildType {
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(default)]
+    pub named: bool,
 }
@@ -62,7 +62,7 @@ impl LanguageTraitImpl for LangTypeScript {
             let mut parser = parser.borrow_mut();
             let bytes = text.as_ref();
             let tree = parser.parse(bytes, None)?;
-            Some(Box::new(TreeSitterParseTree { tree }) as Box<dyn ParseTree>)
+            Some(Box::new(TreeSitterParseTree::new(tree, bytes)) as Box<dyn ParseTree>)
         })
     }
 